@@ -64,6 +64,59 @@ fn push_big_combo(c: &mut Criterion) {
     });
 }
 
+fn extend_big_combo(c: &mut Criterion) {
+    c.bench_function("extend_big_combo", |b| {
+        b.iter(|| {
+            const VEC: ComboVec<i32, 2048> = combo_vec![];
+            let mut my_arr = VEC;
+            black_box(&mut my_arr).extend(black_box(0..2048));
+        });
+    });
+}
+
+fn extend_from_slice_big_combo(c: &mut Criterion) {
+    let src = (0..2048).collect::<Vec<i32>>();
+
+    c.bench_function("extend_from_slice_big_combo", |b| {
+        b.iter(|| {
+            const VEC: ComboVec<i32, 2048> = combo_vec![];
+            let mut my_arr = VEC;
+            black_box(&mut my_arr).extend_from_slice(black_box(&src));
+        });
+    });
+}
+
+fn extend_spill_combo(c: &mut Criterion) {
+    c.bench_function("extend_spill_combo", |b| {
+        b.iter(|| {
+            let mut my_arr = ComboVec::<i32, 8>::new();
+            black_box(&mut my_arr).extend(black_box(0..2048));
+        });
+    });
+}
+
+fn push_spill_combo(c: &mut Criterion) {
+    c.bench_function("push_spill_combo", |b| {
+        b.iter(|| {
+            let mut my_arr = ComboVec::<i32, 8>::new();
+            for i in 0..2048 {
+                black_box(&mut my_arr).push(black_box(i));
+            }
+        });
+    });
+}
+
+fn extend_from_slice_spill_combo(c: &mut Criterion) {
+    let src = (0..2048).collect::<Vec<i32>>();
+
+    c.bench_function("extend_from_slice_spill_combo", |b| {
+        b.iter(|| {
+            let mut my_arr = ComboVec::<i32, 8>::new();
+            black_box(&mut my_arr).extend_from_slice(black_box(&src));
+        });
+    });
+}
+
 fn push_big_arr(c: &mut Criterion) {
     c.bench_function("push_big_arr", |b| {
         b.iter(|| {
@@ -76,6 +129,68 @@ fn push_big_arr(c: &mut Criterion) {
     });
 }
 
+// Criterion only measures time, so the size of each storage goes in the benchmark name.
+fn slot_id<A>(slot: &str) -> String {
+    format!("{slot}/{}_bytes", size_of::<A>())
+}
+
+fn slot_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("slot_push");
+    group.bench_function(slot_id::<ReArr<u32, 2048>>("option"), |b| {
+        b.iter(|| {
+            let mut my_arr = ReArr::<u32, 2048>::new();
+            for i in 0..2048 {
+                black_box(&mut my_arr).push(black_box(i));
+            }
+        });
+    });
+    group.bench_function(slot_id::<ReArr<u32, 2048, u32>>("dense"), |b| {
+        b.iter(|| {
+            let mut my_arr = ReArr::<u32, 2048, u32>::default();
+            for i in 0..2048 {
+                black_box(&mut my_arr).push(black_box(i));
+            }
+        });
+    });
+    group.finish();
+}
+
+fn slot_pop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("slot_pop");
+    group.bench_function(slot_id::<ReArr<f64, 2048>>("option"), |b| {
+        let mut full = ReArr::<f64, 2048>::new();
+        full.extend((0..2048).map(f64::from));
+        b.iter(|| {
+            let mut my_arr = full.clone();
+            while black_box(&mut my_arr).pop().is_some() {}
+        });
+    });
+    group.bench_function(slot_id::<ReArr<f64, 2048, f64>>("dense"), |b| {
+        let mut full = ReArr::<f64, 2048, f64>::default();
+        full.extend((0..2048).map(f64::from));
+        b.iter(|| {
+            let mut my_arr = full.clone();
+            while black_box(&mut my_arr).pop().is_some() {}
+        });
+    });
+    group.finish();
+}
+
+fn slot_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("slot_clone");
+    let mut option = ReArr::<u32, 2048>::new();
+    option.extend(0..2048);
+    group.bench_function(slot_id::<ReArr<u32, 2048>>("option"), |b| {
+        b.iter(|| black_box(option.clone()));
+    });
+    let mut dense = ReArr::<u32, 2048, u32>::default();
+    dense.extend(0..2048);
+    group.bench_function(slot_id::<ReArr<u32, 2048, u32>>("dense"), |b| {
+        b.iter(|| black_box(dense.clone()));
+    });
+    group.finish();
+}
+
 fn normal_push(c: &mut Criterion) {
     c.bench_function("normal_push", |b| {
         b.iter(|| {
@@ -225,6 +340,11 @@ criterion_group!(news, new, new_from_arr);
 criterion_group!(
     pushes,
     push_big_combo,
+    extend_big_combo,
+    extend_from_slice_big_combo,
+    push_spill_combo,
+    extend_spill_combo,
+    extend_from_slice_spill_combo,
     push_big_arr,
     normal_push,
     normal_push_precap,
@@ -240,6 +360,7 @@ criterion_group!(
     smallvec_push_big
 );
 criterion_group!(arrayvec, arrayvec_push_big);
+criterion_group!(slots, slot_push, slot_pop, slot_clone);
 criterion_group!(
     pop,
     arr_pop_big,
@@ -247,4 +368,4 @@ criterion_group!(
     arrayvec_pop_big,
     smallvec_pop_big
 );
-criterion_main!(pop, arrayvec, smallvec, gets, news, pushes);
+criterion_main!(pop, arrayvec, smallvec, gets, news, pushes, slots);
@@ -0,0 +1,22 @@
+#[cfg(feature = "alloc")]
+use crate::ComboVec;
+use crate::ReArr;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, T: Arbitrary<'a>, const N: usize> Arbitrary<'a> for ReArr<T, N> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(0..=N)?;
+        (0..len).map(|_| T::arbitrary(u)).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: Arbitrary<'a>, const N: usize> Arbitrary<'a> for ComboVec<T, N> {
+    /// Generate a [`ComboVec`] that is equally likely to fit on the stack or to have spilled onto the heap.
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(0..=N.saturating_mul(2).saturating_add(1))?;
+        (0..len).map(|_| T::arbitrary(u)).collect()
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(feature = "alloc")]
+use crate::ComboVec;
+use crate::ReArr;
+use bincode::{
+    de::{BorrowDecoder, Decoder},
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+    BorrowDecode, Decode, Encode,
+};
+use core::mem::size_of;
+
+// Sequences are written like bincode writes a `Vec`: a `u64` length, then each element.
+fn encode_seq<'a, T: Encode + 'a, E: Encoder>(
+    len: usize,
+    iter: impl Iterator<Item = &'a T>,
+    encoder: &mut E,
+) -> Result<(), EncodeError> {
+    (len as u64).encode(encoder)?;
+    iter.into_iter().try_for_each(|val| val.encode(encoder))
+}
+
+fn decode_len<D: Decoder>(decoder: &mut D) -> Result<usize, DecodeError> {
+    let len = u64::decode(decoder)?;
+    usize::try_from(len).map_err(|_| DecodeError::OutsideUsizeRange(len))
+}
+
+fn decode_re_arr<T, D: Decoder, const N: usize>(
+    decoder: &mut D,
+    mut decode: impl FnMut(&mut D) -> Result<T, DecodeError>,
+) -> Result<ReArr<T, N>, DecodeError> {
+    let len = decode_len(decoder)?;
+    if len > N {
+        return Err(DecodeError::Other(
+            "sequence is longer than the capacity of the ReArr",
+        ));
+    }
+
+    decoder.claim_container_read::<T>(len)?;
+
+    let mut arr = ReArr::new();
+    for _ in 0..len {
+        // the container read was already claimed
+        decoder.unclaim_bytes_read(size_of::<T>());
+        arr.push(decode(decoder)?);
+    }
+
+    Ok(arr)
+}
+
+#[cfg(feature = "alloc")]
+fn decode_combo_vec<T, D: Decoder, const N: usize>(
+    decoder: &mut D,
+    mut decode: impl FnMut(&mut D) -> Result<T, DecodeError>,
+) -> Result<ComboVec<T, N>, DecodeError> {
+    let len = decode_len(decoder)?;
+    decoder.claim_container_read::<T>(len)?;
+
+    let mut vec = ComboVec::new();
+    for _ in 0..len {
+        // the container read was already claimed
+        decoder.unclaim_bytes_read(size_of::<T>());
+        vec.push(decode(decoder)?);
+    }
+
+    Ok(vec)
+}
+
+impl<T: Encode, const N: usize> Encode for ReArr<T, N> {
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encode_seq(self.len(), self.iter(), encoder)
+    }
+}
+
+impl<Context, T: Decode<Context>, const N: usize> Decode<Context> for ReArr<T, N> {
+    /// Decode a length-prefixed sequence into a [`ReArr`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DecodeError::Other`] if the sequence is longer than `N`.
+    #[inline]
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        decode_re_arr(decoder, T::decode)
+    }
+}
+
+impl<'de, Context, T: BorrowDecode<'de, Context>, const N: usize> BorrowDecode<'de, Context>
+    for ReArr<T, N>
+{
+    /// Decode a length-prefixed sequence into a [`ReArr`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DecodeError::Other`] if the sequence is longer than `N`.
+    #[inline]
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        decode_re_arr(decoder, T::borrow_decode)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Encode, const N: usize> Encode for ComboVec<T, N> {
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encode_seq(self.len(), self.iter(), encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Context, T: Decode<Context>, const N: usize> Decode<Context> for ComboVec<T, N> {
+    #[inline]
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        decode_combo_vec(decoder, T::decode)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, Context, T: BorrowDecode<'de, Context>, const N: usize> BorrowDecode<'de, Context>
+    for ComboVec<T, N>
+{
+    #[inline]
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        decode_combo_vec(decoder, T::borrow_decode)
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(feature = "alloc")]
+use crate::ComboVec;
+use crate::ReArr;
+use borsh::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+// Sequences are written like borsh writes a `Vec`: a little-endian `u32` length, then each element.
+fn serialize_seq<'a, T: BorshSerialize + 'a, W: Write>(
+    len: usize,
+    iter: impl Iterator<Item = &'a T>,
+    writer: &mut W,
+) -> Result<()> {
+    let len = u32::try_from(len)
+        .map_err(|_| Error::new(ErrorKind::InvalidData, "sequence is too long"))?;
+    len.serialize(writer)?;
+    iter.into_iter().try_for_each(|val| val.serialize(writer))
+}
+
+fn deserialize_len<R: Read>(reader: &mut R) -> Result<usize> {
+    let len = u32::deserialize_reader(reader)?;
+    usize::try_from(len).map_err(|_| Error::new(ErrorKind::InvalidData, "sequence is too long"))
+}
+
+impl<T: BorshSerialize, const N: usize> BorshSerialize for ReArr<T, N> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        serialize_seq(self.len(), self.iter(), writer)
+    }
+}
+
+impl<T: BorshDeserialize, const N: usize> BorshDeserialize for ReArr<T, N> {
+    /// Read a length-prefixed sequence into a [`ReArr`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`ErrorKind::InvalidData`] error if the sequence is longer than `N`.
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let len = deserialize_len(reader)?;
+        if len > N {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "sequence is longer than the capacity of the ReArr",
+            ));
+        }
+
+        let mut arr = Self::new();
+        for _ in 0..len {
+            arr.push(T::deserialize_reader(reader)?);
+        }
+
+        Ok(arr)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BorshSerialize, const N: usize> BorshSerialize for ComboVec<T, N> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        serialize_seq(self.len(), self.iter(), writer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BorshDeserialize, const N: usize> BorshDeserialize for ComboVec<T, N> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let len = deserialize_len(reader)?;
+
+        // the length isn't trusted to pre-allocate the heap, elements are only stored once they're read
+        let mut vec = Self::new();
+        for _ in 0..len {
+            vec.push(T::deserialize_reader(reader)?);
+        }
+
+        Ok(vec)
+    }
+}
@@ -0,0 +1,1629 @@
+use crate::{combo_slice::ComboSlice, ComboVec};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::{IntoIter as VecIter, Vec},
+};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter::{self, Chain, Flatten, Take},
+    mem,
+    ops::{self, RangeBounds},
+};
+
+/// A [`ComboVec`] that keeps its inline storage in a [`Box`], allocated the first time an element is pushed.
+///
+/// For a large `N`, a [`ComboVec`] is kilobytes in size and gets copied every time it's moved.
+/// A [`BoxedComboVec`] is only a few pointers in size no matter what `N` is,
+/// and still only makes one allocation for the first `N` elements before spilling onto a [`Vec`].
+///
+/// Clearing or truncating keeps the inline storage allocated for reuse.
+///
+/// It has the same methods as a [`ComboVec`], so one can replace the other without changing any call sites,
+/// along with [`BoxedComboVec::is_inline_allocated`] and conversions in both directions.
+/// The only exceptions are [`ComboVec::from_arr`] and the [`combo_vec!`](crate::combo_vec) macro,
+/// which build the inline storage on the stack.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{BoxedComboVec, ComboVec};
+///
+/// let mut boxed = BoxedComboVec::<i32, 2048>::new();
+/// assert!(!boxed.is_inline_allocated());
+/// assert!(size_of_val(&boxed) < size_of::<ComboVec<i32, 2048>>());
+///
+/// boxed.extend(0..2050);
+/// assert!(boxed.is_inline_allocated());
+/// assert_eq!(boxed.stack_len(), 2048);
+/// assert_eq!(boxed.heap_len(), 2);
+/// assert_eq!(boxed[2049], 2049);
+/// ```
+pub struct BoxedComboVec<T, const N: usize> {
+    slots: Option<Box<[Option<T>; N]>>,
+    stack_len: usize,
+    vec: Vec<T>,
+}
+
+// Collect the slots straight into a heap allocation,
+// since `Box::new` would build the whole array on the stack first.
+#[inline]
+fn box_slots<T, const N: usize>(slots: impl Iterator<Item = Option<T>>) -> Box<[Option<T>; N]> {
+    let slots: Box<[Option<T>]> = slots.chain(iter::repeat_with(|| None)).take(N).collect();
+    slots
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("exactly N slots were collected"))
+}
+
+impl<T: Clone, const N: usize> Clone for BoxedComboVec<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slots: self
+                .slots
+                .as_ref()
+                .map(|slots| box_slots(slots.iter().cloned())),
+            stack_len: self.stack_len,
+            vec: self.vec.clone(),
+        }
+    }
+}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for BoxedComboVec<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for BoxedComboVec<T, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for BoxedComboVec<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq + Eq, const N: usize> Eq for BoxedComboVec<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for BoxedComboVec<T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter().for_each(|x| x.hash(state));
+    }
+}
+
+impl<T, const N: usize> Default for BoxedComboVec<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> BoxedComboVec<T, N> {
+    /// Create a new, empty [`BoxedComboVec`] without allocating.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// const EMPTY: BoxedComboVec<String, 4096> = BoxedComboVec::new();
+    ///
+    /// let boxed = EMPTY;
+    /// assert!(boxed.is_empty());
+    /// assert!(!boxed.is_inline_allocated());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            slots: None,
+            stack_len: 0,
+            vec: Vec::new(),
+        }
+    }
+
+    #[inline]
+    fn stack(&self) -> &[Option<T>] {
+        self.slots
+            .as_deref()
+            .map_or(&[], |slots| &slots[..self.stack_len])
+    }
+
+    #[inline]
+    fn stack_mut(&mut self) -> &mut [Option<T>] {
+        self.parts_mut().0
+    }
+
+    // Borrow the occupied stack slots and the heap at the same time.
+    #[inline]
+    fn parts_mut(&mut self) -> (&mut [Option<T>], &mut Vec<T>) {
+        let stack = self
+            .slots
+            .as_deref_mut()
+            .map_or(&mut [][..], |slots| &mut slots[..self.stack_len]);
+
+        (stack, &mut self.vec)
+    }
+
+    #[inline]
+    fn slots_mut(&mut self) -> &mut [Option<T>; N] {
+        self.slots.get_or_insert_with(|| box_slots(iter::empty()))
+    }
+
+    #[inline]
+    fn push_stack(&mut self, val: T) {
+        let idx = self.stack_len;
+        self.slots_mut()[idx] = Some(val);
+        self.stack_len += 1;
+    }
+
+    #[inline]
+    fn pop_stack(&mut self) -> Option<T> {
+        let last = self.stack_mut().last_mut()?.take();
+        self.stack_len -= 1;
+        last
+    }
+
+    // Move elements from `iter` into the free stack slots, only allocating them if there is an element to store.
+    fn extend_stack(&mut self, iter: impl Iterator<Item = T>) {
+        let mut iter = iter.peekable();
+        if self.stack_len == N || iter.peek().is_none() {
+            return;
+        }
+
+        let start = self.stack_len;
+        let filled = self.slots_mut()[start..]
+            .iter_mut()
+            .zip(iter)
+            .map(|(slot, val)| *slot = Some(val))
+            .count();
+        self.stack_len += filled;
+    }
+
+    // Move elements from the start of the heap into any free slots on the stack,
+    // restoring the invariant that the stack is full before the heap is used.
+    fn fill_stack_from_heap(&mut self) {
+        let free_slots = N - self.stack_len;
+        if free_slots != 0 && !self.vec.is_empty() {
+            let mut vec = mem::take(&mut self.vec);
+            self.extend_stack(vec.drain(..free_slots.min(vec.len())));
+            self.vec = vec;
+        }
+    }
+
+    /// Allocate more memory to what can be stored on the heap.
+    ///
+    /// Note that this function is not required to add more items, but can be used as an optimization to avoid excessive reallocations when adding many items.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.reserve(10);
+    /// assert!(boxed.capacity() >= 13);
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// Push an element to the end of the array.
+    ///
+    /// The inline storage is allocated by the first push, and once it is full the element is pushed to the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = BoxedComboVec::<i32, 2>::new();
+    /// boxed.push(1);
+    /// boxed.push(2);
+    /// assert!(!boxed.spilled());
+    ///
+    /// boxed.push(3);
+    /// assert!(boxed.spilled());
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, val: T) {
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_push(self.len() + 1, N);
+
+        self.push_untracked(val);
+    }
+
+    /// Push an element to the end of the array, recording it in the given [`SpillStats`](crate::SpillStats)
+    /// instead of the global ones.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{BoxedComboVec, SpillStats};
+    ///
+    /// static STATS: SpillStats = SpillStats::new();
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.push_tracked(4, &STATS);
+    /// assert_eq!(STATS.snapshot().spills(), 1);
+    /// ```
+    #[cfg(feature = "stats")]
+    #[inline]
+    pub fn push_tracked(&mut self, val: T, stats: &crate::SpillStats) {
+        stats.record_push(self.len() + 1, N);
+        self.push_untracked(val);
+    }
+
+    #[inline]
+    fn push_untracked(&mut self, val: T) {
+        if self.stack_len < N {
+            self.push_stack(val);
+        } else {
+            self.vec.push(val);
+        }
+    }
+
+    /// Remove the last element from the array and return it, or None if it is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// assert_eq!(boxed.pop(), Some(3));
+    /// assert_eq!(boxed.pop(), Some(2));
+    /// assert_eq!(boxed.pop(), Some(1));
+    /// assert_eq!(boxed.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.vec.is_empty() {
+            self.pop_stack()
+        } else {
+            self.vec.pop()
+        }
+    }
+
+    /// Get any element from the array as a reference, returning `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// assert_eq!(boxed.get(0), Some(&1));
+    /// assert_eq!(boxed.get(2), Some(&3));
+    /// assert_eq!(boxed.get(3), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if idx < N {
+            self.stack().get(idx)?.as_ref()
+        } else {
+            self.vec.get(idx - N)
+        }
+    }
+
+    /// Get any element from the array as a mutable reference, `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    ///
+    /// if let Some(x) = boxed.get_mut(2) {
+    ///     *x = 4;
+    /// }
+    ///
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        if idx < N {
+            self.stack_mut().get_mut(idx)?.as_mut()
+        } else {
+            self.vec.get_mut(idx - N)
+        }
+    }
+
+    /// Whether or not the inline storage has been allocated yet.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = BoxedComboVec::<i32, 3>::new();
+    /// assert!(!boxed.is_inline_allocated());
+    ///
+    /// boxed.push(1);
+    /// boxed.clear();
+    /// assert!(boxed.is_inline_allocated());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_inline_allocated(&self) -> bool {
+        self.slots.is_some()
+    }
+
+    /// Whether or not where are any elements allocated on the heap instead of the inline storage
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// assert_eq!(boxed.spilled(), false);
+    ///
+    /// boxed.push(4);
+    /// assert_eq!(boxed.spilled(), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        !self.vec.is_empty()
+    }
+
+    /// How many elements are currently stored in the inline storage.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// assert_eq!(boxed.stack_len(), 3);
+    /// boxed.push(4);
+    /// assert_eq!(boxed.stack_len(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn stack_len(&self) -> usize {
+        self.stack_len
+    }
+
+    /// How many elements are currently stored on the heap after the inline storage.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// assert_eq!(boxed.heap_len(), 0);
+    /// boxed.push(4);
+    /// assert_eq!(boxed.heap_len(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn heap_len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// How many elements are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// assert_eq!(boxed.len(), 3);
+    /// boxed.push(4);
+    /// assert_eq!(boxed.len(), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.stack_len + self.vec.len()
+    }
+
+    /// How many elements can be stored in the inline storage.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = BoxedComboVec::<i32, 3>::new();
+    /// assert_eq!(boxed.stack_capacity(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn stack_capacity(&self) -> usize {
+        N
+    }
+
+    /// How many elements can be stored on the currently allocated heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = BoxedComboVec::<i32, 3>::new();
+    /// assert_eq!(boxed.heap_capacity(), 0);
+    /// boxed.extend([1, 2, 3, 4]);
+    /// assert!(boxed.heap_capacity() >= 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn heap_capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// How many elements can be stored without reallocating anything.
+    ///
+    /// The inline storage counts towards this even before it is allocated.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = BoxedComboVec::<i32, 3>::new();
+    /// assert_eq!(boxed.capacity(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.stack_capacity() + self.heap_capacity()
+    }
+
+    /// Check if the array is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = BoxedComboVec::<i32, 3>::new();
+    /// assert!(boxed.is_empty());
+    /// boxed.push(1);
+    /// assert!(!boxed.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the first element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// assert_eq!(boxed.first(), Some(&1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Get the first element as a mutable reference, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// *boxed.first_mut().unwrap() = 4;
+    /// assert_eq!(boxed.first(), Some(&4));
+    /// ```
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Get the last element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// assert_eq!(boxed.last(), Some(&3));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.vec
+            .last()
+            .or_else(|| self.stack().last().and_then(Option::as_ref))
+    }
+
+    /// Get the last element as a mutable reference, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// *boxed.last_mut().unwrap() = 4;
+    /// assert_eq!(boxed.last(), Some(&4));
+    /// ```
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        let (stack, vec) = self.parts_mut();
+        vec.last_mut()
+            .or_else(|| stack.last_mut().and_then(Option::as_mut))
+    }
+
+    /// Reduces the length to the given length, dropping the rest of the elements.
+    ///
+    /// If `len` is greater than the current length, this has no effect.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = (1..=5).collect::<BoxedComboVec<i32, 2>>();
+    /// boxed.truncate(3);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3]);
+    ///
+    /// boxed.truncate(1);
+    /// assert_eq!(boxed.to_vec(), vec![1]);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= N {
+            self.vec.truncate(len - N);
+        } else if len < self.stack_len {
+            self.vec.clear();
+            self.stack_mut()[len..]
+                .iter_mut()
+                .for_each(|slot| *slot = None);
+            self.stack_len = len;
+        }
+    }
+
+    /// Remove all elements from the array, keeping the inline storage allocated.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = (1..=5).collect::<BoxedComboVec<i32, 2>>();
+    /// boxed.clear();
+    /// assert!(boxed.is_empty());
+    /// assert!(boxed.is_inline_allocated());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Retains only the elements specified by the predicate, preserving their order.
+    ///
+    /// Elements are moved from the heap back into the inline storage as space frees up.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = (1..=6).collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.retain(|x| x % 2 == 0);
+    /// assert_eq!(boxed.to_vec(), vec![2, 4, 6]);
+    /// assert!(!boxed.spilled());
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|x| f(x));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// Elements are moved from the heap back into the inline storage as space frees up.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = (1..=6).collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.retain_mut(|x| {
+    ///     *x += 1;
+    ///     *x % 2 == 0
+    /// });
+    /// assert_eq!(boxed.to_vec(), vec![2, 4, 6]);
+    /// assert!(!boxed.spilled());
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let (stack, vec) = self.parts_mut();
+
+        let mut kept = 0;
+        for i in 0..stack.len() {
+            let mut val = crate::Slot::take(&mut stack[i]);
+            if f(&mut val) {
+                stack[kept] = Some(val);
+                kept += 1;
+            }
+        }
+
+        vec.retain_mut(f);
+        self.stack_len = kept;
+        self.fill_stack_from_heap();
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    ///
+    /// If the inline storage is full, its last element is moved to the heap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.insert(1, 4);
+    /// assert_eq!(boxed.to_vec(), vec![1, 4, 2, 3]);
+    /// assert_eq!(boxed.heap_len(), 1);
+    /// boxed.insert(4, 5);
+    /// assert_eq!(boxed.to_vec(), vec![1, 4, 2, 3, 5]);
+    /// ```
+    pub fn insert(&mut self, index: usize, val: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if index >= N {
+            self.vec.insert(index - N, val);
+        } else {
+            if self.stack_len == N {
+                if let Some(last) = self.pop_stack() {
+                    self.vec.insert(0, last);
+                }
+            }
+
+            self.push_stack(val);
+            self.stack_mut()[index..].rotate_right(1);
+        }
+    }
+
+    /// Removes and returns the element at position with a valid index, shifting all elements after it to the left.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = (1..=5).collect::<BoxedComboVec<i32, 3>>();
+    /// assert_eq!(boxed.remove(1), 2);
+    /// assert_eq!(boxed.to_vec(), vec![1, 3, 4, 5]);
+    /// assert_eq!(boxed.remove(3), 5);
+    /// assert_eq!(boxed.to_vec(), vec![1, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> T {
+        if index >= N {
+            self.vec.remove(index - N)
+        } else {
+            assert!(
+                index < self.stack_len,
+                "removal index (is {index}) should be < len (is {})",
+                self.stack_len
+            );
+
+            self.stack_mut()[index..].rotate_left(1);
+            let val = self
+                .pop_stack()
+                .unwrap_or_else(|| unreachable!("the stack holds the removed element"));
+
+            if !self.vec.is_empty() {
+                let first = self.vec.remove(0);
+                self.push_stack(first);
+            }
+
+            val
+        }
+    }
+
+    /// Removes an element from the `BoxedComboVec` and returns it.
+    ///
+    /// The removed element is replaced by the last element of the `BoxedComboVec`.
+    ///
+    /// This does not preserve ordering, but is O(1). If you need to preserve the element order, use remove instead.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = (1..=6).collect::<BoxedComboVec<i32, 3>>();
+    /// assert_eq!(boxed.swap_remove(1), 2);
+    /// assert_eq!(boxed.to_vec(), vec![1, 6, 3, 4, 5]);
+    /// assert_eq!(boxed.swap_remove(3), 4);
+    /// assert_eq!(boxed.to_vec(), vec![1, 6, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        if index >= N {
+            self.vec.swap_remove(index - N)
+        } else if let Some(last_value) = self.vec.pop() {
+            mem::replace(
+                crate::Slot::get_mut(&mut self.stack_mut()[index]),
+                last_value,
+            )
+        } else {
+            assert!(
+                index < self.stack_len,
+                "swap_remove index (is {index}) should be < len (is {})",
+                self.stack_len
+            );
+
+            let last = self.stack_len - 1;
+            self.stack_mut().swap(index, last);
+            self.pop_stack()
+                .unwrap_or_else(|| unreachable!("the stack holds the removed element"))
+        }
+    }
+
+    /// Swaps two elements in the `BoxedComboVec`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = (1..=5).collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.swap(0, 4);
+    /// boxed.swap(1, 2);
+    /// assert_eq!(boxed.to_vec(), vec![5, 3, 2, 4, 1]);
+    /// ```
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let (stack, vec) = self.parts_mut();
+        match (a < N, b < N) {
+            (true, true) => stack.swap(a, b),
+            (false, false) => vec.swap(a - N, b - N),
+            (true, false) => mem::swap(crate::Slot::get_mut(&mut stack[a]), &mut vec[b - N]),
+            (false, true) => mem::swap(crate::Slot::get_mut(&mut stack[b]), &mut vec[a - N]),
+        }
+    }
+
+    /// Removes all but the first of consecutive elements that resolve to the same key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [10, 20, 21, 30, 20].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(boxed.to_vec(), vec![10, 20, 30, 20]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive elements that satisfy the given equality relation.
+    ///
+    /// The `same_bucket` function is passed the element being considered,
+    /// followed by the last element that was kept.
+    /// If it returns `true`, the element being considered is removed.
+    ///
+    /// Elements are moved from the heap back into the inline storage as space frees up.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = ["foo", "bar", "Bar", "baz", "bar"]
+    ///     .into_iter()
+    ///     .collect::<BoxedComboVec<&str, 3>>();
+    /// boxed.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(boxed.to_vec(), vec!["foo", "bar", "baz", "bar"]);
+    /// assert_eq!(boxed.stack_len(), 3);
+    /// assert_eq!(boxed.heap_len(), 1);
+    /// ```
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let (stack, vec) = self.parts_mut();
+
+        let mut kept = stack.len().min(1);
+        for i in 1..stack.len() {
+            let mut val = crate::Slot::take(&mut stack[i]);
+            if !same_bucket(&mut val, crate::Slot::get_mut(&mut stack[kept - 1])) {
+                stack[kept] = Some(val);
+                kept += 1;
+            }
+        }
+
+        // the first elements of the heap are compared against the last element kept on the stack
+        if let Some(last_kept) = kept
+            .checked_sub(1)
+            .map(|idx| crate::Slot::get_mut(&mut stack[idx]))
+        {
+            let removed = vec
+                .iter_mut()
+                .position(|val| !same_bucket(val, last_kept))
+                .unwrap_or(vec.len());
+            vec.drain(..removed);
+        }
+
+        vec.dedup_by(same_bucket);
+        self.stack_len = kept;
+        self.fill_stack_from_heap();
+    }
+
+    /// Get an iterator over the elements of the array.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// let mut iter = boxed.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.stack().iter().flatten().chain(self.vec.iter())
+    }
+
+    /// Get an iterator over the elements of the array, returning mutable references.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// for i in boxed.iter_mut() {
+    ///    *i += 1;
+    /// }
+    /// assert_eq!(boxed.to_vec(), vec![2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        let (stack, vec) = self.parts_mut();
+        stack.iter_mut().flatten().chain(vec.iter_mut())
+    }
+
+    /// Extend this array with all the elements from the given iterator.
+    ///
+    /// The inline storage is filled first, then the rest of the elements are moved onto the heap in one go.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = BoxedComboVec::<i32, 3>::new();
+    /// boxed.extend(vec![1, 2, 3, 4]);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        #[cfg(feature = "stats")]
+        let old_len = self.len();
+
+        self.extend_untracked(iter);
+
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_growth(old_len, self.len(), N);
+    }
+
+    // Extend without recording into the spill stats, for bulk operations that record the whole call at once.
+    #[inline]
+    fn extend_untracked<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        self.extend_stack(&mut iter);
+
+        self.vec
+            .reserve(iter.size_hint().0.saturating_sub(N - self.stack_len));
+        self.vec.extend(iter);
+    }
+
+    /// Clone and append every element of a slice.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = BoxedComboVec::<i32, 2>::new();
+    /// boxed.extend_from_slice(&[1, 2, 3, 4]);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3, 4]);
+    /// assert_eq!(boxed.heap_len(), 2);
+    /// ```
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        #[cfg(feature = "stats")]
+        let old_len = self.len();
+
+        let (stack, heap) = other.split_at(other.len().min(N - self.stack_len));
+        self.extend_stack(stack.iter().cloned());
+        self.vec.extend_from_slice(heap);
+
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_growth(old_len, self.len(), N);
+    }
+
+    /// Replace the contents of this [`BoxedComboVec`] with clones of the elements of a slice.
+    ///
+    /// Elements that are already in the [`BoxedComboVec`] are overwritten with [`Clone::clone_from`],
+    /// so they can reuse any resources they own, and the heap keeps its capacity.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.clone_from_slice(&[7, 8, 9, 10]);
+    /// assert_eq!(boxed.to_vec(), vec![7, 8, 9, 10]);
+    ///
+    /// boxed.clone_from_slice(&[1]);
+    /// assert_eq!(boxed.to_vec(), vec![1]);
+    /// ```
+    #[inline]
+    pub fn clone_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        self.truncate(src.len());
+        let (init, tail) = src.split_at(self.len());
+        self.iter_mut()
+            .zip(init)
+            .for_each(|(dst, src)| dst.clone_from(src));
+        self.extend_from_slice(tail);
+    }
+
+    /// Splits the [`BoxedComboVec`] into two at the given index.
+    ///
+    /// Returns a new [`BoxedComboVec`] containing the elements in the range `[at, len)`,
+    /// leaving the elements `[0, at)` in this one.
+    /// Both halves fill their inline storage before using the heap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = (1..=6).collect::<BoxedComboVec<i32, 3>>();
+    ///
+    /// let other = boxed.split_off(2);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2]);
+    /// assert_eq!(other.to_vec(), vec![3, 4, 5, 6]);
+    /// assert_eq!(other.stack_len(), 3);
+    /// assert_eq!(other.heap_len(), 1);
+    /// ```
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        if at >= N {
+            self.vec.split_off(at - N).into_iter().collect()
+        } else {
+            let vec = mem::take(&mut self.vec);
+            let other = self.stack_mut()[at..]
+                .iter_mut()
+                .filter_map(Option::take)
+                .chain(vec)
+                .collect();
+            self.stack_len = at;
+            other
+        }
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// `other` may have a different inline capacity than `self`, and keeps its inline storage allocated.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// let mut other = [4, 5, 6].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    ///
+    /// boxed.append(&mut other);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append<const M: usize>(&mut self, other: &mut BoxedComboVec<T, M>) {
+        #[cfg(feature = "stats")]
+        let old_len = self.len();
+
+        self.extend_untracked(other.stack_mut().iter_mut().filter_map(Option::take));
+        other.stack_len = 0;
+
+        if self.len() >= N {
+            self.vec.append(&mut other.vec);
+        } else {
+            self.extend_untracked(other.vec.drain(..));
+        }
+
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_growth(old_len, self.len(), N);
+    }
+
+    /// Move the elements into a [`BoxedComboVec`] with a different inline capacity, reusing the heap allocation.
+    ///
+    /// When growing, elements are moved from the heap into the inline storage.
+    /// When shrinking, elements that no longer fit in the inline storage are moved onto the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = (1..=5).collect::<BoxedComboVec<i32, 3>>();
+    ///
+    /// let bigger: BoxedComboVec<i32, 8> = boxed.into_capacity();
+    /// assert_eq!(bigger.to_vec(), vec![1, 2, 3, 4, 5]);
+    /// assert!(!bigger.spilled());
+    ///
+    /// let smaller: BoxedComboVec<i32, 2> = bigger.into_capacity();
+    /// assert_eq!(smaller.to_vec(), vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(smaller.heap_len(), 3);
+    /// ```
+    #[doc(alias = "resize_capacity")]
+    #[must_use]
+    pub fn into_capacity<const M: usize>(mut self) -> BoxedComboVec<T, M> {
+        let mut vec = mem::take(&mut self.vec);
+        let stack = self.stack_mut();
+        if stack.len() > M {
+            vec.splice(0..0, stack[M..].iter_mut().filter_map(Option::take));
+        }
+
+        let mut boxed = BoxedComboVec::new();
+        boxed.extend_stack(stack.iter_mut().filter_map(Option::take));
+
+        let free_slots = M - boxed.stack_len;
+        boxed.extend_stack(vec.drain(..free_slots.min(vec.len())));
+        boxed.vec = vec;
+        boxed
+    }
+
+    /// Convert into a [`ComboVec`], moving the inline storage back onto the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// let combo = boxed.into_combo_vec();
+    /// assert_eq!(combo.to_vec(), vec![1, 2, 3]);
+    /// assert_eq!(combo.heap_len(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_combo_vec(self) -> ComboVec<T, N> {
+        let mut combo = ComboVec::new();
+        combo.extend(self.slots.into_iter().flat_map(|slots| {
+            let slots: Box<[Option<T>]> = slots;
+            slots.into_vec().into_iter().flatten()
+        }));
+        combo.vec = self.vec;
+        combo
+    }
+
+    /// Convert into a [`Vec`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// assert_eq!(boxed.into_vec(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+
+    /// Get this [`BoxedComboVec`] represented as a [`Vec`], borrowing data instead of moving it.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// assert_eq!(boxed.ref_vec(), vec![&1, &2, &3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn ref_vec(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    /// Get a [`ComboSlice`] view into a range of the elements,
+    /// regardless of whether they are stored inline or on the heap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = (1..=5).collect::<BoxedComboVec<i32, 3>>();
+    /// assert_eq!(boxed.slice(2..4).to_vec(), vec![3, 4]);
+    /// assert_eq!(boxed.slice(..).len(), 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> ComboSlice<'_, T, N> {
+        ComboSlice::new(self.stack(), &self.vec, range)
+    }
+
+    /// Get an iterator over `chunk_size` elements at a time, starting at the beginning.
+    ///
+    /// The last chunk will be shorter if `chunk_size` does not divide the length.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = (1..=5).collect::<BoxedComboVec<i32, 3>>();
+    ///
+    /// let mut iter = boxed.chunks(2);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1, 2]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![3, 4]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![5]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        self.slice(..).chunks(chunk_size)
+    }
+
+    /// Get an iterator over exactly `chunk_size` elements at a time, starting at the beginning.
+    ///
+    /// If `chunk_size` does not divide the length, the last up to `chunk_size - 1`
+    /// elements are skipped.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = (1..=5).collect::<BoxedComboVec<i32, 3>>();
+    ///
+    /// let mut iter = boxed.chunks_exact(2);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1, 2]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![3, 4]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn chunks_exact(
+        &self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        self.slice(..).chunks_exact(chunk_size)
+    }
+
+    /// Get an iterator over `chunk_size` elements at a time, starting at the end.
+    ///
+    /// The last chunk will be shorter if `chunk_size` does not divide the length.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = (1..=5).collect::<BoxedComboVec<i32, 3>>();
+    ///
+    /// let mut iter = boxed.rchunks(2);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![4, 5]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![2, 3]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn rchunks(&self, chunk_size: usize) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        self.slice(..).rchunks(chunk_size)
+    }
+
+    /// Get an iterator over all contiguous windows of length `size`.
+    ///
+    /// The windows overlap. If the length is less than `size`, the iterator returns no values.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = (1..=4).collect::<BoxedComboVec<i32, 3>>();
+    ///
+    /// let mut iter = boxed.windows(3);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1, 2, 3]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![2, 3, 4]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        self.slice(..).windows(size)
+    }
+
+    /// Get an iterator over the groups of elements separated by elements that match `pred`.
+    ///
+    /// The matched element is not contained in the groups.
+    /// Like [`slice::split`], empty groups are returned for adjacent or leading/trailing matches.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [10, 40, 33, 20].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    ///
+    /// let mut iter = boxed.split(|num| num % 3 == 0);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![10, 40]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![20]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn split<'a, F: FnMut(&T) -> bool + 'a>(
+        &'a self,
+        pred: F,
+    ) -> impl Iterator<Item = ComboSlice<'a, T, N>> + 'a {
+        self.slice(..).split(pred)
+    }
+
+    /// Get an iterator over runs of elements,
+    /// where `pred` returns `true` for every pair of consecutive elements in a run.
+    ///
+    /// This is the equivalent of [`slice::chunk_by`] (formerly known as `group_by`).
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 1, 2, 2, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    ///
+    /// let mut iter = boxed.chunk_by(|a, b| a == b);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1, 1]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![2, 2, 2]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![3]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn chunk_by<'a, F: FnMut(&T, &T) -> bool + 'a>(
+        &'a self,
+        pred: F,
+    ) -> impl Iterator<Item = ComboSlice<'a, T, N>> + 'a {
+        self.slice(..).chunk_by(pred)
+    }
+}
+
+impl<T: Clone, const N: usize> BoxedComboVec<T, N> {
+    /// Clone all the elements into a [`Vec`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    /// Resizes the [`BoxedComboVec`] in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the [`BoxedComboVec`] is extended by the
+    /// difference, with each additional slot filled with `val`.
+    ///
+    /// If `new_len` is less than `len`, the [`BoxedComboVec`] is truncated.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.resize(5, 4);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3, 4, 4]);
+    /// boxed.resize(2, 5);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2]);
+    /// boxed.resize(5, 6);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 6, 6, 6]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, val: T) {
+        if new_len >= N {
+            if self.stack_len < N {
+                self.extend_stack(iter::repeat_n(val.clone(), N - self.stack_len));
+            }
+
+            self.vec.resize(new_len - N, val);
+        } else {
+            self.truncate(new_len);
+            self.extend_stack(iter::repeat_n(val, new_len - self.stack_len));
+        }
+    }
+
+    /// Resizes the [`BoxedComboVec`] in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the [`BoxedComboVec`] is extended by the
+    /// difference, with each additional slot filled with the result of calling
+    /// the closure `f`.
+    ///
+    /// If `new_len` is less than `len`, the [`BoxedComboVec`] is truncated.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.resize_with(5, Default::default);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3, 0, 0]);
+    /// boxed.resize_with(2, Default::default);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2]);
+    /// boxed.resize_with(5, Default::default);
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 0, 0, 0]);
+    /// ```
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        if new_len >= N {
+            self.extend_stack(iter::repeat_with(&mut f).take(N - self.stack_len));
+            self.vec.resize_with(new_len - N, f);
+        } else {
+            self.truncate(new_len);
+            self.extend_stack(iter::repeat_with(f).take(new_len - self.stack_len));
+        }
+    }
+
+    /// Create a new [`BoxedComboVec`] by cloning and joining together the elements of every part, in order.
+    ///
+    /// The parts may have a different inline capacity than the result.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let parts = [
+    ///     [1, 2].into_iter().collect::<BoxedComboVec<i32, 2>>(),
+    ///     [3, 4].into_iter().collect(),
+    /// ];
+    /// let joined = BoxedComboVec::<i32, 3>::concat(&parts);
+    ///
+    /// assert_eq!(joined.to_vec(), vec![1, 2, 3, 4]);
+    /// assert_eq!(joined.stack_len(), 3);
+    /// assert_eq!(joined.heap_len(), 1);
+    /// ```
+    #[must_use]
+    pub fn concat<const M: usize>(parts: &[BoxedComboVec<T, M>]) -> Self {
+        let len: usize = parts.iter().map(BoxedComboVec::len).sum();
+
+        let mut joined = Self::new();
+        joined.reserve(len.saturating_sub(N));
+        joined.extend(parts.iter().flat_map(BoxedComboVec::iter).cloned());
+        joined
+    }
+}
+
+impl<T: PartialEq, const N: usize> BoxedComboVec<T, N> {
+    /// Removes consecutive repeated elements.
+    ///
+    /// If the [`BoxedComboVec`] is sorted, this removes all duplicates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let mut boxed = [1, 1, 2, 2, 3, 3, 4].into_iter().collect::<BoxedComboVec<i32, 3>>();
+    /// boxed.dedup();
+    /// assert_eq!(boxed.to_vec(), vec![1, 2, 3, 4]);
+    /// assert_eq!(boxed.stack_len(), 3);
+    /// assert_eq!(boxed.heap_len(), 1);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+}
+
+impl<T: ToString, const N: usize> BoxedComboVec<T, N> {
+    /// Joins the [`BoxedComboVec`] into a string with a separator.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::BoxedComboVec;
+    ///
+    /// let boxed = [1, 2, 3].into_iter().collect::<BoxedComboVec<i32, 2>>();
+    /// assert_eq!(boxed.join(", "), "1, 2, 3");
+    /// ```
+    #[must_use]
+    pub fn join(&self, sep: &str) -> String {
+        self.iter()
+            .enumerate()
+            .fold(String::with_capacity(self.len()), |mut s, (i, item)| {
+                if i != 0 {
+                    s.push_str(sep);
+                }
+
+                s.push_str(&item.to_string());
+                s
+            })
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for BoxedComboVec<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &Self::Output {
+        if idx < N {
+            self.stack()[idx].as_ref().unwrap()
+        } else {
+            &self.vec[idx - N]
+        }
+    }
+}
+
+impl<T, const N: usize> ops::IndexMut<usize> for BoxedComboVec<T, N> {
+    #[inline]
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        if idx < N {
+            self.stack_mut()[idx].as_mut().unwrap()
+        } else {
+            &mut self.vec[idx - N]
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for BoxedComboVec<T, N> {
+    type Item = T;
+    type IntoIter = Chain<Flatten<Take<VecIter<Option<T>>>>, VecIter<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        // Unsizing to a boxed slice lets the slots be moved out without copying the array onto the stack.
+        let slots: Box<[Option<T>]> = match self.slots {
+            Some(slots) => slots,
+            None => Box::new([]),
+        };
+
+        slots
+            .into_vec()
+            .into_iter()
+            .take(self.stack_len)
+            .flatten()
+            .chain(self.vec)
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for BoxedComboVec<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut boxed = Self::new();
+        boxed.extend(iter);
+        boxed
+    }
+}
+
+impl<T, const N: usize> From<ComboVec<T, N>> for BoxedComboVec<T, N> {
+    #[inline]
+    fn from(combo: ComboVec<T, N>) -> Self {
+        let stack_len = combo.arr.len();
+
+        Self {
+            slots: (stack_len > 0).then(|| box_slots(combo.arr.arr.into_iter())),
+            stack_len,
+            vec: combo.vec,
+        }
+    }
+}
+
+impl<T, const N: usize> From<BoxedComboVec<T, N>> for ComboVec<T, N> {
+    #[inline]
+    fn from(boxed: BoxedComboVec<T, N>) -> Self {
+        boxed.into_combo_vec()
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for BoxedComboVec<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("BoxedComboVec")
+            .field("stack", &self.stack())
+            .field("vec", &self.vec)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Debug, const N: usize> Display for BoxedComboVec<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
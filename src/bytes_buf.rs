@@ -0,0 +1,277 @@
+// The readers lay the stack bytes out contiguously so `chunk` can hand them out as a slice.
+//
+// `BufMut` is an `unsafe trait`, which this crate can't implement since it forbids unsafe code.
+// The writers lend out the `BufMut` implementations of `bytes` itself instead,
+// over a scratch copy of the free stack slots, and then copy the written bytes back.
+
+#[cfg(feature = "alloc")]
+use crate::ComboVec;
+use crate::{ReArr, Slot};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use bytes::buf::Chain;
+use bytes::{Buf, BufMut};
+
+impl<S: Slot<u8>, const N: usize> ReArr<u8, N, S> {
+    /// Write to the end of the array through [`BufMut`].
+    ///
+    /// `f` gets a [`BufMut`] over the free slots, so [`BufMut::remaining_mut`] is `N - len`,
+    /// and writing past it panics like it does for a `&mut [u8]`.
+    /// Everything `f` writes is appended once it returns.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use bytes::BufMut;
+    /// use combo_vec::ReArr;
+    ///
+    /// let mut frame = ReArr::<u8, 8>::new();
+    /// frame.with_buf_mut(|buf| {
+    ///     assert_eq!(buf.remaining_mut(), 8);
+    ///     buf.put_u16(0x1234);
+    ///     buf.put_slice(b"hi");
+    /// });
+    ///
+    /// assert_eq!(frame.len(), 4);
+    /// assert!(frame.iter().eq(&[0x12, 0x34, b'h', b'i']));
+    /// ```
+    #[inline]
+    pub fn with_buf_mut<R, F: FnOnce(&mut dyn BufMut) -> R>(&mut self, f: F) -> R {
+        let mut scratch = [0; N];
+        let free = N - self.len();
+
+        let mut buf = &mut scratch[..free];
+        let out = f(&mut buf);
+        let written = free - buf.len();
+
+        self.extend(scratch[..written].iter().copied());
+        out
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> ComboVec<u8, N> {
+    /// Write to the end of the [`ComboVec`] through [`BufMut`].
+    ///
+    /// `f` gets a [`BufMut`] that fills the free stack slots first, then grows the heap,
+    /// so it never runs out of room.
+    /// Everything `f` writes is appended once it returns.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use bytes::BufMut;
+    /// use combo_vec::ComboVec;
+    ///
+    /// let mut frame = ComboVec::<u8, 4>::new();
+    /// frame.with_buf_mut(|buf| {
+    ///     buf.put_u32(0x0102_0304);
+    ///     buf.put_u16(0x0506);
+    /// });
+    ///
+    /// assert_eq!(frame.stack_len(), 4);
+    /// assert_eq!(frame.heap_len(), 2);
+    /// assert_eq!(frame.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn with_buf_mut<R, F: FnOnce(&mut Chain<&mut [u8], &mut Vec<u8>>) -> R>(
+        &mut self,
+        f: F,
+    ) -> R {
+        #[cfg(feature = "stats")]
+        let old_len = self.len();
+
+        let mut scratch = [0; N];
+        // Once anything is on the heap, new bytes have to go after it.
+        let free = if self.vec.is_empty() {
+            N - self.arr.len()
+        } else {
+            0
+        };
+
+        let mut buf = (&mut scratch[..free]).chain_mut(&mut self.vec);
+        let out = f(&mut buf);
+        let written = free - buf.first_ref().len();
+
+        self.arr.extend(scratch[..written].iter().copied());
+
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_growth(old_len, self.len(), N);
+
+        out
+    }
+}
+
+/// A consuming reader over the bytes of a [`ReArr<u8, N>`] that implements [`bytes::Buf`].
+///
+/// To write into a [`ReArr<u8, N>`] through [`BufMut`], use [`ReArr::with_buf_mut`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use bytes::Buf;
+/// use combo_vec::{re_arr, ReArrBuf};
+///
+/// let mut buf = ReArrBuf::new(re_arr![0x12u8, 0x34, 0x56; None]);
+/// assert_eq!(buf.get_u16(), 0x1234);
+/// assert_eq!(buf.remaining(), 1);
+/// assert_eq!(buf.chunk(), &[0x56]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ReArrBuf<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+    pos: usize,
+}
+
+impl<const N: usize> ReArrBuf<N> {
+    /// Create a reader that starts at the first byte of the array.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use bytes::Buf;
+    /// use combo_vec::{re_arr, ReArrBuf};
+    ///
+    /// let buf = ReArrBuf::new(re_arr![1u8, 2, 3]);
+    /// assert_eq!(buf.remaining(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(arr: ReArr<u8, N>) -> Self {
+        let len = arr.len();
+        let mut bytes = [0; N];
+        bytes.iter_mut().zip(arr).for_each(|(dst, src)| *dst = src);
+
+        Self { bytes, len, pos: 0 }
+    }
+}
+
+impl<const N: usize> From<ReArr<u8, N>> for ReArrBuf<N> {
+    #[inline]
+    fn from(arr: ReArr<u8, N>) -> Self {
+        Self::new(arr)
+    }
+}
+
+impl<const N: usize> Buf for ReArrBuf<N> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len - self.pos
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        &self.bytes[self.pos..self.len]
+    }
+
+    /// Skip over `cnt` bytes.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `cnt` is greater than the number of remaining bytes.
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.remaining(),
+            "cannot advance past the end of the buffer"
+        );
+        self.pos += cnt;
+    }
+}
+
+/// A consuming reader over the bytes of a [`ComboVec<u8, N>`] that implements [`bytes::Buf`].
+///
+/// [`Buf::chunk`] returns the unread bytes of the stack, then the unread bytes of the heap.
+///
+/// To write into a [`ComboVec<u8, N>`] through [`BufMut`], use [`ComboVec::with_buf_mut`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use bytes::Buf;
+/// use combo_vec::{ComboVec, ComboVecBuf};
+///
+/// let frame = (1..=6).collect::<ComboVec<u8, 4>>();
+/// let mut buf = ComboVecBuf::new(frame);
+///
+/// assert_eq!(buf.chunk(), &[1, 2, 3, 4]);
+/// assert_eq!(buf.get_u32(), 0x0102_0304);
+/// assert_eq!(buf.chunk(), &[5, 6]);
+/// assert_eq!(buf.copy_to_bytes(2).as_ref(), &[5, 6]);
+/// assert!(!buf.has_remaining());
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct ComboVecBuf<const N: usize> {
+    stack: ReArrBuf<N>,
+    heap: Vec<u8>,
+    pos: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> ComboVecBuf<N> {
+    /// Create a reader that starts at the first byte of the [`ComboVec`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use bytes::Buf;
+    /// use combo_vec::{combo_vec, ComboVecBuf};
+    ///
+    /// let buf = ComboVecBuf::new(combo_vec![1u8, 2, 3]);
+    /// assert_eq!(buf.remaining(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(vec: ComboVec<u8, N>) -> Self {
+        Self {
+            stack: ReArrBuf::new(vec.arr),
+            heap: vec.vec,
+            pos: 0,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<ComboVec<u8, N>> for ComboVecBuf<N> {
+    #[inline]
+    fn from(vec: ComboVec<u8, N>) -> Self {
+        Self::new(vec)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> Buf for ComboVecBuf<N> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.stack.remaining() + self.heap.len() - self.pos
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        if self.stack.has_remaining() {
+            self.stack.chunk()
+        } else {
+            &self.heap[self.pos..]
+        }
+    }
+
+    /// Skip over `cnt` bytes.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `cnt` is greater than the number of remaining bytes.
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.remaining(),
+            "cannot advance past the end of the buffer"
+        );
+
+        let from_stack = cnt.min(self.stack.remaining());
+        self.stack.advance(from_stack);
+        self.pos += cnt - from_stack;
+    }
+}
@@ -0,0 +1,383 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    mem::{align_of, size_of},
+};
+
+/// Recommends an inline capacity `N` from a set of observed lengths.
+///
+/// Record the lengths your vectors reach (for example, `ComboVec::len()` at the end of each test case),
+/// then ask which `N` would have kept a given percentile of them on the stack,
+/// and how many bytes each [`ComboVec<T, N>`](crate::ComboVec) would take up with that `N`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::CapacityAdvisor;
+///
+/// let advisor = (1..=100).collect::<CapacityAdvisor>();
+///
+/// let p95 = advisor.recommend::<u64>(95).unwrap();
+/// assert_eq!(p95.inline_capacity(), 95);
+/// assert_eq!(p95.covered(), 95);
+///
+/// let p99 = advisor.recommend::<u64>(99).unwrap();
+/// assert_eq!(p99.inline_capacity(), 99);
+/// assert!(p99.stack_bytes() > p95.stack_bytes());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CapacityAdvisor {
+    // The number of samples seen for each length.
+    lens: BTreeMap<usize, usize>,
+    samples: usize,
+}
+
+impl CapacityAdvisor {
+    /// Create an advisor with no samples.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::CapacityAdvisor;
+    ///
+    /// let advisor = CapacityAdvisor::new();
+    /// assert!(advisor.is_empty());
+    /// assert_eq!(advisor.recommend::<u8>(99), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            lens: BTreeMap::new(),
+            samples: 0,
+        }
+    }
+
+    /// Record one observed length.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, CapacityAdvisor};
+    ///
+    /// let mut advisor = CapacityAdvisor::new();
+    /// let vec = combo_vec![1, 2, 3];
+    /// advisor.record(vec.len());
+    /// assert_eq!(advisor.samples(), 1);
+    /// ```
+    #[inline]
+    pub fn record(&mut self, len: usize) {
+        *self.lens.entry(len).or_default() += 1;
+        self.samples += 1;
+    }
+
+    /// How many lengths have been recorded.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::CapacityAdvisor;
+    ///
+    /// let advisor = [1, 1, 2].into_iter().collect::<CapacityAdvisor>();
+    /// assert_eq!(advisor.samples(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Check if no lengths have been recorded.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::CapacityAdvisor;
+    ///
+    /// assert!(CapacityAdvisor::new().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.samples == 0
+    }
+
+    /// The longest recorded length, or `None` if there are no samples.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::CapacityAdvisor;
+    ///
+    /// let advisor = [3, 9, 4].into_iter().collect::<CapacityAdvisor>();
+    /// assert_eq!(advisor.max_len(), Some(9));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn max_len(&self) -> Option<usize> {
+        self.lens.last_key_value().map(|(&len, _)| len)
+    }
+
+    /// How many samples would fit on the stack with an inline capacity of `inline_capacity`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::CapacityAdvisor;
+    ///
+    /// let advisor = [1, 2, 2, 8].into_iter().collect::<CapacityAdvisor>();
+    /// assert_eq!(advisor.covered(2), 3);
+    /// assert_eq!(advisor.covered(8), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn covered(&self, inline_capacity: usize) -> usize {
+        self.lens
+            .range(..=inline_capacity)
+            .map(|(_, &count)| count)
+            .sum()
+    }
+
+    /// The smallest length that at least `covered / out_of` of the samples are no longer than,
+    /// or `None` if there are no samples.
+    ///
+    /// This is the nearest-rank percentile, so `quantile(999, 1000)` is the p99.9 length.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `out_of` is zero or `covered` is greater than `out_of`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::CapacityAdvisor;
+    ///
+    /// let advisor = (1..=1000).collect::<CapacityAdvisor>();
+    /// assert_eq!(advisor.quantile(999, 1000), Some(999));
+    /// assert_eq!(advisor.quantile(1, 2), Some(500));
+    /// ```
+    #[must_use]
+    pub fn quantile(&self, covered: usize, out_of: usize) -> Option<usize> {
+        assert!(
+            out_of != 0 && covered <= out_of,
+            "quantile must be a fraction between 0 and 1"
+        );
+
+        if self.is_empty() {
+            return None;
+        }
+
+        let needed = (self.samples as u128 * covered as u128).div_ceil(out_of as u128);
+        let needed = usize::try_from(needed).unwrap_or(self.samples);
+        if needed == 0 {
+            return Some(0);
+        }
+
+        let mut seen = 0;
+        self.lens.iter().find_map(|(&len, &count)| {
+            seen += count;
+            (seen >= needed).then_some(len)
+        })
+    }
+
+    /// The smallest length that at least `percent`% of the samples are no longer than,
+    /// or `None` if there are no samples.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `percent` is greater than 100.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::CapacityAdvisor;
+    ///
+    /// let advisor = [1, 2, 3, 4, 100].into_iter().collect::<CapacityAdvisor>();
+    /// assert_eq!(advisor.percentile(80), Some(4));
+    /// assert_eq!(advisor.percentile(100), Some(100));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn percentile(&self, percent: u8) -> Option<usize> {
+        self.quantile(usize::from(percent), 100)
+    }
+
+    /// Recommend the inline capacity that keeps `percent`% of the samples on the stack,
+    /// along with what it costs to store a [`ComboVec<T, N>`](crate::ComboVec) with that capacity.
+    ///
+    /// Returns `None` if there are no samples.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `percent` is greater than 100.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::CapacityAdvisor;
+    ///
+    /// let advisor = [2, 3, 3, 4, 40].into_iter().collect::<CapacityAdvisor>();
+    /// let recommendation = advisor.recommend::<u32>(80).unwrap();
+    ///
+    /// assert_eq!(recommendation.inline_capacity(), 4);
+    /// assert_eq!(recommendation.covered(), 4);
+    /// assert_eq!(recommendation.samples(), 5);
+    /// assert_eq!(recommendation.stack_bytes(), size_of::<combo_vec::ComboVec<u32, 4>>());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn recommend<T>(&self, percent: u8) -> Option<CapacityRecommendation<T>> {
+        let inline_capacity = self.percentile(percent)?;
+
+        Some(CapacityRecommendation {
+            inline_capacity,
+            covered: self.covered(inline_capacity),
+            samples: self.samples,
+            elem: PhantomData,
+        })
+    }
+}
+
+impl Extend<usize> for CapacityAdvisor {
+    #[inline]
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|len| self.record(len));
+    }
+}
+
+impl FromIterator<usize> for CapacityAdvisor {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut advisor = Self::new();
+        advisor.extend(iter);
+        advisor
+    }
+}
+
+/// An inline capacity recommended by a [`CapacityAdvisor`], for a [`ComboVec`](crate::ComboVec) of `T`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::CapacityAdvisor;
+///
+/// let advisor = [1, 2, 2, 3].into_iter().collect::<CapacityAdvisor>();
+/// let recommendation = advisor.recommend::<u64>(75).unwrap();
+/// assert_eq!(
+///     recommendation.to_string(),
+///     format!("N = 2 keeps 3 of 4 samples (75%) on the stack in {} bytes", recommendation.stack_bytes()),
+/// );
+/// ```
+pub struct CapacityRecommendation<T> {
+    inline_capacity: usize,
+    covered: usize,
+    samples: usize,
+    elem: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for CapacityRecommendation<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CapacityRecommendation<T> {}
+
+impl<T> PartialEq for CapacityRecommendation<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inline_capacity == other.inline_capacity
+            && self.covered == other.covered
+            && self.samples == other.samples
+    }
+}
+
+impl<T> Eq for CapacityRecommendation<T> {}
+
+impl<T> core::fmt::Debug for CapacityRecommendation<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("CapacityRecommendation")
+            .field("inline_capacity", &self.inline_capacity)
+            .field("covered", &self.covered)
+            .field("samples", &self.samples)
+            .field("stack_bytes", &self.stack_bytes())
+            .finish()
+    }
+}
+
+impl<T> CapacityRecommendation<T> {
+    /// The recommended inline capacity `N`.
+    #[must_use]
+    #[inline]
+    pub const fn inline_capacity(&self) -> usize {
+        self.inline_capacity
+    }
+
+    /// How many of the samples fit on the stack with this capacity.
+    #[must_use]
+    #[inline]
+    pub const fn covered(&self) -> usize {
+        self.covered
+    }
+
+    /// How many samples the recommendation was based on.
+    #[must_use]
+    #[inline]
+    pub const fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// The estimated `size_of::<ComboVec<T, N>>()` with the recommended `N`.
+    #[must_use]
+    #[inline]
+    pub const fn stack_bytes(&self) -> usize {
+        combo_vec_size_of::<T>(self.inline_capacity)
+    }
+}
+
+impl<T> Display for CapacityRecommendation<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "N = {} keeps {} of {} samples ({}%) on the stack in {} bytes",
+            self.inline_capacity,
+            self.covered,
+            self.samples,
+            self.covered * 100 / self.samples,
+            self.stack_bytes(),
+        )
+    }
+}
+
+/// Estimate `size_of::<ComboVec<T, N>>()` for an `N` that is only known at runtime.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{combo_vec_size_of, ComboVec};
+///
+/// assert_eq!(combo_vec_size_of::<u8>(16), size_of::<ComboVec<u8, 16>>());
+/// assert_eq!(combo_vec_size_of::<String>(4), size_of::<ComboVec<String, 4>>());
+/// ```
+#[must_use]
+pub const fn combo_vec_size_of<T>(inline_capacity: usize) -> usize {
+    const fn max(a: usize, b: usize) -> usize {
+        if a > b {
+            a
+        } else {
+            b
+        }
+    }
+
+    // A `ComboVec` holds a `ReArr` (the slots and their length) and a `Vec`,
+    // and each struct is padded to a multiple of its alignment.
+    let re_arr_align = max(align_of::<Option<T>>(), align_of::<usize>());
+    let re_arr_size = (inline_capacity * size_of::<Option<T>>() + size_of::<usize>())
+        .next_multiple_of(re_arr_align);
+
+    (re_arr_size + size_of::<Vec<T>>()).next_multiple_of(max(re_arr_align, align_of::<Vec<T>>()))
+}
@@ -0,0 +1,424 @@
+use crate::ComboVec;
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem,
+    ops::{Deref, DerefMut},
+};
+
+/// A max-heap priority queue that stores up to `N` elements on the stack, and moves to the heap for more.
+///
+/// This mirrors [`alloc::collections::BinaryHeap`],
+/// where [`ComboBinaryHeap::pop`] always returns the greatest element.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{combo_vec, ComboBinaryHeap};
+///
+/// const EMPTY: ComboBinaryHeap<i32, 4> = ComboBinaryHeap::new();
+///
+/// let mut heap = EMPTY;
+/// heap.push(3);
+/// heap.push(7);
+/// heap.push(1);
+/// assert_eq!(heap.peek(), Some(&7));
+///
+/// assert_eq!(heap.pop(), Some(7));
+/// assert_eq!(heap.pop(), Some(3));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.pop(), None);
+///
+/// // Build a heap in O(n) from an existing ComboVec
+/// let heap = ComboBinaryHeap::from(combo_vec![2, 9, 4]);
+/// assert_eq!(heap.into_sorted_vec().to_vec(), vec![2, 4, 9]);
+/// ```
+pub struct ComboBinaryHeap<T, const N: usize> {
+    data: ComboVec<T, N>,
+}
+
+impl<T: Clone, const N: usize> Clone for ComboBinaryHeap<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ComboBinaryHeap<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ComboBinaryHeap<T, N> {
+    /// Create a new, empty [`ComboBinaryHeap`] with the ability for `N` elements to be stored on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let heap = ComboBinaryHeap::<i32, 4>::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: ComboVec::new(),
+        }
+    }
+
+    /// How many elements are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let heap: ComboBinaryHeap<i32, 1> = [1, 2].into_iter().collect();
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let heap = ComboBinaryHeap::<i32, 1>::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Whether or not there are any elements stored on the heap instead of the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let mut heap = ComboBinaryHeap::<i32, 1>::new();
+    /// heap.push(1);
+    /// assert!(!heap.spilled());
+    /// heap.push(2);
+    /// assert!(heap.spilled());
+    /// ```
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.data.spilled()
+    }
+
+    /// Get the greatest element, or `None` if the heap is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let mut heap = ComboBinaryHeap::<i32, 2>::new();
+    /// assert_eq!(heap.peek(), None);
+    /// heap.push(1);
+    /// heap.push(5);
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Remove all elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let mut heap: ComboBinaryHeap<i32, 1> = [1, 2].into_iter().collect();
+    /// heap.clear();
+    /// assert!(heap.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Remove all elements, returning them in arbitrary order.
+    ///
+    /// The heap is empty once this returns, even if the iterator isn't fully consumed.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let mut heap: ComboBinaryHeap<i32, 1> = [1, 2, 3].into_iter().collect();
+    /// assert_eq!(heap.drain().count(), 3);
+    /// assert!(heap.is_empty());
+    /// ```
+    #[inline]
+    pub fn drain(&mut self) -> <ComboVec<T, N> as IntoIterator>::IntoIter {
+        mem::replace(&mut self.data, ComboVec::new()).into_iter()
+    }
+
+    /// Get an iterator over the elements, in arbitrary order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let heap: ComboBinaryHeap<i32, 2> = [1, 3, 2].into_iter().collect();
+    /// assert_eq!(heap.iter().sum::<i32>(), 6);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.data.iter()
+    }
+
+    /// Get the underlying [`ComboVec`], in arbitrary order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let heap: ComboBinaryHeap<i32, 2> = [1, 3, 2].into_iter().collect();
+    /// let mut vec = heap.into_vec().into_vec();
+    /// vec.sort();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_vec(self) -> ComboVec<T, N> {
+        self.data
+    }
+}
+
+impl<T: Ord, const N: usize> ComboBinaryHeap<T, N> {
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if self.data[idx] <= self.data[parent] {
+                break;
+            }
+
+            self.data.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down_range(&mut self, mut idx: usize, end: usize) {
+        loop {
+            let mut child = 2 * idx + 1;
+            if child >= end {
+                break;
+            }
+
+            if child + 1 < end && self.data[child] < self.data[child + 1] {
+                child += 1;
+            }
+
+            if self.data[idx] >= self.data[child] {
+                break;
+            }
+
+            self.data.swap(idx, child);
+            idx = child;
+        }
+    }
+
+    /// Add an element to the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let mut heap = ComboBinaryHeap::<i32, 1>::new();
+    /// heap.push(1);
+    /// heap.push(3);
+    /// assert_eq!(heap.peek(), Some(&3));
+    /// ```
+    #[inline]
+    pub fn push(&mut self, val: T) {
+        self.data.push(val);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    /// Remove and return the greatest element, or `None` if the heap is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let mut heap: ComboBinaryHeap<i32, 1> = [1, 3].into_iter().collect();
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.data.len();
+        if len > 1 {
+            self.data.swap(0, len - 1);
+        }
+
+        let val = self.data.pop()?;
+        self.sift_down_range(0, self.data.len());
+        Some(val)
+    }
+
+    /// Get a mutable reference to the greatest element, or `None` if the heap is empty.
+    ///
+    /// The heap is restored once the returned guard is dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{ComboBinaryHeap, ComboBinaryHeapPeekMut};
+    ///
+    /// let mut heap: ComboBinaryHeap<i32, 2> = [1, 5, 3].into_iter().collect();
+    /// if let Some(mut top) = heap.peek_mut() {
+    ///     *top = 0;
+    /// }
+    /// assert_eq!(heap.peek(), Some(&3));
+    ///
+    /// let top = heap.peek_mut().unwrap();
+    /// assert_eq!(ComboBinaryHeapPeekMut::pop(top), 3);
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<ComboBinaryHeapPeekMut<'_, T, N>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(ComboBinaryHeapPeekMut { heap: self })
+        }
+    }
+
+    /// Get the elements as a [`ComboVec`] sorted in ascending order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let heap: ComboBinaryHeap<i32, 2> = [4, 1, 3, 2].into_iter().collect();
+    /// assert_eq!(heap.into_sorted_vec().to_vec(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn into_sorted_vec(mut self) -> ComboVec<T, N> {
+        for end in (1..self.data.len()).rev() {
+            self.data.swap(0, end);
+            self.sift_down_range(0, end);
+        }
+
+        self.data
+    }
+
+    /// Add all elements of an iterator to the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBinaryHeap;
+    ///
+    /// let mut heap = ComboBinaryHeap::<i32, 1>::new();
+    /// heap.extend([1, 4, 2]);
+    /// assert_eq!(heap.peek(), Some(&4));
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| self.push(val));
+    }
+}
+
+/// A guard that holds a mutable reference to the greatest element of a [`ComboBinaryHeap`].
+///
+/// Returned by [`ComboBinaryHeap::peek_mut`].
+pub struct ComboBinaryHeapPeekMut<'a, T: Ord, const N: usize> {
+    heap: &'a mut ComboBinaryHeap<T, N>,
+}
+
+impl<T: Ord, const N: usize> ComboBinaryHeapPeekMut<'_, T, N> {
+    /// Remove the peeked element from the heap and return it.
+    #[must_use]
+    #[inline]
+    pub fn pop(this: Self) -> T {
+        let val = this.heap.pop();
+        drop(this);
+        // the guard is only handed out for a non-empty heap
+        val.unwrap_or_else(|| unreachable!())
+    }
+}
+
+impl<T: Ord, const N: usize> Deref for ComboBinaryHeapPeekMut<'_, T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.heap.data[0]
+    }
+}
+
+impl<T: Ord, const N: usize> DerefMut for ComboBinaryHeapPeekMut<'_, T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.heap.data[0]
+    }
+}
+
+impl<T: Ord, const N: usize> Drop for ComboBinaryHeapPeekMut<'_, T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        self.heap.sift_down_range(0, self.heap.len());
+    }
+}
+
+impl<T: Ord, const N: usize> From<ComboVec<T, N>> for ComboBinaryHeap<T, N> {
+    /// Build a heap from the elements in O(n) time.
+    fn from(data: ComboVec<T, N>) -> Self {
+        let mut heap = Self { data };
+        let len = heap.len();
+        for idx in (0..len / 2).rev() {
+            heap.sift_down_range(idx, len);
+        }
+
+        heap
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ComboBinaryHeap<T, N> {
+    type Item = T;
+    type IntoIter = <ComboVec<T, N> as IntoIterator>::IntoIter;
+
+    /// Get an iterator over the elements, in arbitrary order.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for ComboBinaryHeap<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<ComboVec<T, N>>())
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ComboBinaryHeap<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
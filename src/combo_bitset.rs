@@ -0,0 +1,428 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter, ops,
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of bit indices that stores `N` words of 64 bits on the stack, and moves to the heap for higher indices.
+///
+/// Indices below `N * 64` are stored inline.
+/// Inserting a higher index grows a heap [`Vec<u64>`] that holds the words after the inline ones.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::ComboBitSet;
+///
+/// const EMPTY: ComboBitSet<2> = ComboBitSet::new();
+///
+/// let mut flags = EMPTY;
+/// assert!(flags.insert(3));
+/// assert!(flags.insert(127));
+/// assert!(!flags.spilled());
+///
+/// // Spill to the heap
+/// flags.insert(1000);
+/// assert!(flags.spilled());
+/// assert_eq!(flags.iter().collect::<Vec<_>>(), vec![3, 127, 1000]);
+///
+/// // Shrink back onto the stack
+/// flags.remove(1000);
+/// assert!(!flags.spilled());
+/// ```
+pub struct ComboBitSet<const N: usize> {
+    words: [u64; N],
+    // never ends in a zero word
+    heap: Vec<u64>,
+}
+
+impl<const N: usize> Clone for ComboBitSet<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words,
+            heap: self.heap.clone(),
+        }
+    }
+}
+
+impl<const N: usize> PartialEq for ComboBitSet<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words && self.heap == other.heap
+    }
+}
+
+impl<const N: usize> Eq for ComboBitSet<N> {}
+
+impl<const N: usize> Hash for ComboBitSet<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.words.hash(state);
+        self.heap.hash(state);
+    }
+}
+
+impl<const N: usize> Default for ComboBitSet<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ComboBitSet<N> {
+    /// Create a new, empty [`ComboBitSet`] with room for `N * 64` bits on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let set = ComboBitSet::<2>::new();
+    /// assert!(set.is_empty());
+    /// assert_eq!(set.stack_capacity(), 128);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            words: [0; N],
+            heap: Vec::new(),
+        }
+    }
+
+    /// How many bits can be stored on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let set = ComboBitSet::<1>::new();
+    /// assert_eq!(set.stack_capacity(), 64);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn stack_capacity(&self) -> usize {
+        N * WORD_BITS
+    }
+
+    /// Whether or not any bits are stored on the heap instead of the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let mut set = ComboBitSet::<1>::new();
+    /// set.insert(63);
+    /// assert!(!set.spilled());
+    /// set.insert(64);
+    /// assert!(set.spilled());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        !self.heap.is_empty()
+    }
+
+    #[inline]
+    fn word(&self, idx: usize) -> u64 {
+        if idx < N {
+            self.words[idx]
+        } else {
+            self.heap.get(idx - N).copied().unwrap_or(0)
+        }
+    }
+
+    #[inline]
+    fn word_mut(&mut self, idx: usize) -> &mut u64 {
+        if idx < N {
+            &mut self.words[idx]
+        } else {
+            let idx = idx - N;
+            if idx >= self.heap.len() {
+                self.heap.resize(idx + 1, 0);
+            }
+
+            &mut self.heap[idx]
+        }
+    }
+
+    #[inline]
+    fn trim_heap(&mut self) {
+        while self.heap.last() == Some(&0) {
+            self.heap.pop();
+        }
+    }
+
+    /// Set a bit, returning whether it was newly set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let mut set = ComboBitSet::<1>::new();
+    /// assert!(set.insert(5));
+    /// assert!(!set.insert(5));
+    /// assert!(set.contains(5));
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, bit: usize) -> bool {
+        let mask = 1 << (bit % WORD_BITS);
+        let word = self.word_mut(bit / WORD_BITS);
+        let added = *word & mask == 0;
+        *word |= mask;
+        added
+    }
+
+    /// Clear a bit, returning whether it was set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let mut set = ComboBitSet::<1>::new();
+    /// set.insert(200);
+    /// assert!(set.remove(200));
+    /// assert!(!set.remove(200));
+    /// assert!(!set.spilled());
+    /// ```
+    pub fn remove(&mut self, bit: usize) -> bool {
+        if !self.contains(bit) {
+            return false;
+        }
+
+        *self.word_mut(bit / WORD_BITS) &= !(1 << (bit % WORD_BITS));
+        self.trim_heap();
+        true
+    }
+
+    /// Check if a bit is set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let mut set = ComboBitSet::<1>::new();
+    /// set.insert(5);
+    /// assert!(set.contains(5));
+    /// assert!(!set.contains(6));
+    /// assert!(!set.contains(10_000));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, bit: usize) -> bool {
+        self.word(bit / WORD_BITS) & (1 << (bit % WORD_BITS)) != 0
+    }
+
+    /// How many bits are set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let set: ComboBitSet<1> = [1, 2, 3, 500].into_iter().collect();
+    /// assert_eq!(set.count_ones(), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .chain(self.heap.iter())
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Check if no bits are set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let mut set = ComboBitSet::<1>::new();
+    /// assert!(set.is_empty());
+    /// set.insert(0);
+    /// assert!(!set.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty() && self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Clear all bits, freeing the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let mut set: ComboBitSet<1> = [1, 2, 300].into_iter().collect();
+    /// set.clear();
+    /// assert!(set.is_empty());
+    /// assert!(!set.spilled());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words = [0; N];
+        self.heap = Vec::new();
+    }
+
+    /// Get an iterator over the set bits, in ascending order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let set: ComboBitSet<1> = [300, 2, 64].into_iter().collect();
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![2, 64, 300]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .chain(self.heap.iter())
+            .enumerate()
+            .flat_map(|(idx, &word)| {
+                let mut word = word;
+                iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    Some(idx * WORD_BITS + bit)
+                })
+            })
+    }
+
+    /// Set every bit from an iterator.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboBitSet;
+    ///
+    /// let mut set = ComboBitSet::<1>::new();
+    /// set.extend([1, 2, 1]);
+    /// assert_eq!(set.count_ones(), 2);
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|bit| {
+            self.insert(bit);
+        });
+    }
+
+    fn combine(&self, other: &Self, f: fn(u64, u64) -> u64) -> Self {
+        let mut words = [0; N];
+        for (idx, word) in words.iter_mut().enumerate() {
+            *word = f(self.words[idx], other.words[idx]);
+        }
+
+        let heap_len = self.heap.len().max(other.heap.len());
+        let heap = (N..N + heap_len)
+            .map(|idx| f(self.word(idx), other.word(idx)))
+            .collect();
+
+        let mut set = Self { words, heap };
+        set.trim_heap();
+        set
+    }
+}
+
+impl<const N: usize> ops::BitAnd for &ComboBitSet<N> {
+    type Output = ComboBitSet<N>;
+
+    /// Get the bits that are set in both sets.
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a & b)
+    }
+}
+
+impl<const N: usize> ops::BitOr for &ComboBitSet<N> {
+    type Output = ComboBitSet<N>;
+
+    /// Get the bits that are set in either set.
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a | b)
+    }
+}
+
+impl<const N: usize> ops::BitXor for &ComboBitSet<N> {
+    type Output = ComboBitSet<N>;
+
+    /// Get the bits that are set in exactly one of the sets.
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a ^ b)
+    }
+}
+
+impl<const N: usize> ops::Sub for &ComboBitSet<N> {
+    type Output = ComboBitSet<N>;
+
+    /// Get the bits that are set in `self` but not in `rhs`.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a & !b)
+    }
+}
+
+impl<const N: usize> ops::BitAndAssign<&Self> for ComboBitSet<N> {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &Self) {
+        *self = &*self & rhs;
+    }
+}
+
+impl<const N: usize> ops::BitOrAssign<&Self> for ComboBitSet<N> {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &Self) {
+        *self = &*self | rhs;
+    }
+}
+
+impl<const N: usize> ops::BitXorAssign<&Self> for ComboBitSet<N> {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &Self) {
+        *self = &*self ^ rhs;
+    }
+}
+
+impl<const N: usize> ops::SubAssign<&Self> for ComboBitSet<N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = &*self - rhs;
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for ComboBitSet<N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const N: usize> Debug for ComboBitSet<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,508 @@
+use crate::{combo_slice::ComboSlice, re_grid::neighbor_coords, ComboVec};
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem, ops,
+};
+
+/// A two-dimensional grid that stores up to `N` cells on the stack, and moves to the heap for more.
+///
+/// Cells are stored row by row and indexed by `(x, y)`, where `x` is the column and `y` is the row.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::ComboGrid;
+///
+/// const EMPTY: ComboGrid<char, 16> = ComboGrid::new();
+///
+/// let mut grid = EMPTY;
+/// grid.resize(3, 2, '.');
+/// grid[(2, 1)] = '#';
+/// assert!(!grid.spilled());
+///
+/// assert_eq!(grid.width(), 3);
+/// assert_eq!(grid.height(), 2);
+/// assert_eq!(grid.column(2).collect::<Vec<_>>(), vec![&'.', &'#']);
+///
+/// let mut grid = grid.transpose();
+/// assert_eq!(grid[(1, 2)], '#');
+///
+/// // Spill to the heap
+/// grid.resize(8, 8, '.');
+/// assert!(grid.spilled());
+/// assert_eq!(grid[(1, 2)], '#');
+/// ```
+pub struct ComboGrid<T, const N: usize> {
+    cells: ComboVec<T, N>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone, const N: usize> Clone for ComboGrid<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            cells: self.cells.clone(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ComboGrid<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.cells == other.cells
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ComboGrid<T, N> {}
+
+impl<T, const N: usize> Default for ComboGrid<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ComboGrid<T, N> {
+    /// Create a new, empty [`ComboGrid`] with the ability for `N` cells to be stored on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let grid = ComboGrid::<i32, 9>::new();
+    /// assert!(grid.is_empty());
+    /// assert_eq!(grid.width(), 0);
+    /// assert_eq!(grid.height(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            cells: ComboVec::new(),
+            width: 0,
+            height: 0,
+        }
+    }
+
+    /// How many columns are in the grid.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 1, 0);
+    /// assert_eq!(grid.width(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// How many rows are in the grid.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 1, 0);
+    /// assert_eq!(grid.height(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// How many cells are in the grid.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 2, 0);
+    /// assert_eq!(grid.len(), 6);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Check if there are no cells.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// assert!(grid.is_empty());
+    /// grid.resize(3, 0, 0);
+    /// assert!(grid.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether or not there are any cells stored on the heap instead of the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 4>::new();
+    /// grid.resize(2, 2, 0);
+    /// assert!(!grid.spilled());
+    /// grid.resize(3, 2, 0);
+    /// assert!(grid.spilled());
+    /// ```
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.cells.spilled()
+    }
+
+    /// Get the cell at `(x, y)` as a reference, returning `None` if it is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(2, 2, 7);
+    /// assert_eq!(grid.get(1, 1), Some(&7));
+    /// assert_eq!(grid.get(2, 1), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Get the cell at `(x, y)` as a mutable reference, returning `None` if it is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(2, 2, 7);
+    /// *grid.get_mut(1, 0).unwrap() = 8;
+    /// assert_eq!(grid[(1, 0)], 8);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Get the row at `y`, returning `None` if it is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(2, 2, 7);
+    /// assert_eq!(grid.row(0).unwrap().to_vec(), vec![7, 7]);
+    /// assert!(grid.row(2).is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn row(&self, y: usize) -> Option<ComboSlice<'_, T, N>> {
+        if y < self.height {
+            Some(self.cells.slice(y * self.width..(y + 1) * self.width))
+        } else {
+            None
+        }
+    }
+
+    /// Get an iterator over the rows, from top to bottom.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 2, 1);
+    /// assert_eq!(grid.rows().map(|row| row.iter().sum::<i32>()).collect::<Vec<_>>(), vec![3, 3]);
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        (0..self.height).map(|y| self.cells.slice(y * self.width..(y + 1) * self.width))
+    }
+
+    /// Get an iterator over the cells of the column at `x`, from top to bottom.
+    ///
+    /// The iterator is empty if `x` is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 2, 1);
+    /// grid[(0, 1)] = 5;
+    /// assert_eq!(grid.column(0).collect::<Vec<_>>(), vec![&1, &5]);
+    /// ```
+    #[inline]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// Get an iterator over the columns, from left to right.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 2, 1);
+    /// grid[(2, 0)] = 5;
+    /// assert_eq!(grid.columns().map(|col| col.sum::<i32>()).collect::<Vec<_>>(), vec![2, 2, 6]);
+    /// ```
+    #[inline]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Get an iterator over every cell and its `(x, y)` coordinates, row by row.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(2, 1, 0);
+    /// assert_eq!(grid.iter().collect::<Vec<_>>(), vec![((0, 0), &0), ((1, 0), &0)]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, val)| ((idx % width, idx / width), val))
+    }
+
+    /// Get an iterator over every cell and its `(x, y)` coordinates, returning mutable references.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<usize, 9>::new();
+    /// grid.resize(3, 2, 0);
+    /// grid.iter_mut().for_each(|((x, y), val)| *val = x + y);
+    /// assert_eq!(grid[(2, 1)], 3);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, val)| ((idx % width, idx / width), val))
+    }
+
+    /// Get an iterator over the up to four cells directly above, left of, right of, and below `(x, y)`,
+    /// along with their coordinates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 3, 0);
+    /// assert_eq!(grid.neighbors(0, 0).count(), 2);
+    /// assert_eq!(grid.neighbors(1, 1).count(), 4);
+    /// assert_eq!(
+    ///     grid.neighbors(0, 1).map(|(pos, _)| pos).collect::<Vec<_>>(),
+    ///     vec![(0, 0), (1, 1), (0, 2)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        neighbor_coords((x, y), self.width, self.height, false)
+            .map(|(nx, ny)| ((nx, ny), &self.cells[ny * self.width + nx]))
+    }
+
+    /// Get an iterator over the up to eight cells surrounding `(x, y)`, including diagonals,
+    /// along with their coordinates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 3, 0);
+    /// assert_eq!(grid.neighbors_with_diagonals(0, 0).count(), 3);
+    /// assert_eq!(grid.neighbors_with_diagonals(1, 1).count(), 8);
+    /// ```
+    #[inline]
+    pub fn neighbors_with_diagonals(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        neighbor_coords((x, y), self.width, self.height, true)
+            .map(|(nx, ny)| ((nx, ny), &self.cells[ny * self.width + nx]))
+    }
+
+    /// Resize the grid to `width` columns and `height` rows, filling new cells by calling `f`.
+    ///
+    /// Cells that fall outside of the new size are dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 4>::new();
+    /// let mut next = 0;
+    /// grid.resize_with(2, 2, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(grid[(1, 1)], 4);
+    /// ```
+    pub fn resize_with<F: FnMut() -> T>(&mut self, width: usize, height: usize, mut f: F) {
+        let old_width = self.width;
+        let old_height = self.height;
+        let mut old = mem::replace(&mut self.cells, ComboVec::new()).into_iter();
+
+        for y in 0..height {
+            if y < old_height {
+                for (x, val) in old.by_ref().take(old_width).enumerate() {
+                    if x < width {
+                        self.cells.push(val);
+                    }
+                }
+
+                for _ in old_width..width {
+                    self.cells.push(f());
+                }
+            } else {
+                for _ in 0..width {
+                    self.cells.push(f());
+                }
+            }
+        }
+
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Swap the rows and columns of the grid, so the cell at `(x, y)` moves to `(y, x)`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 9>::new();
+    /// grid.resize(3, 2, 0);
+    /// grid[(2, 0)] = 1;
+    ///
+    /// let grid = grid.transpose();
+    /// assert_eq!(grid.width(), 2);
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid[(0, 2)], 1);
+    /// ```
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        let width = self.width;
+        let height = self.height;
+        let mut cells: ComboVec<Option<T>, N> = self.cells.into_iter().map(Some).collect();
+
+        Self {
+            cells: (0..width)
+                .flat_map(|x| (0..height).map(move |y| y * width + x))
+                .filter_map(|idx| cells[idx].take())
+                .collect(),
+            width: height,
+            height: width,
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> ComboGrid<T, N> {
+    /// Resize the grid to `width` columns and `height` rows, filling new cells with clones of `val`.
+    ///
+    /// Cells that fall outside of the new size are dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboGrid;
+    ///
+    /// let mut grid = ComboGrid::<i32, 4>::new();
+    /// grid.resize(3, 3, 1);
+    /// grid.resize(2, 3, 0);
+    /// assert_eq!(grid.len(), 6);
+    /// assert!(grid.iter().all(|(_, &val)| val == 1));
+    /// ```
+    #[inline]
+    pub fn resize(&mut self, width: usize, height: usize, val: T) {
+        self.resize_with(width, height, || val.clone());
+    }
+}
+
+impl<T, const N: usize> ops::Index<(usize, usize)> for ComboGrid<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).expect("grid position out of bounds")
+    }
+}
+
+impl<T, const N: usize> ops::IndexMut<(usize, usize)> for ComboGrid<T, N> {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("grid position out of bounds")
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ComboGrid<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ComboGrid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("cells", &self.cells)
+            .finish()
+    }
+}
@@ -0,0 +1,741 @@
+use crate::ComboVec;
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem, ops,
+};
+
+/// Easy creation of a new [`ComboMap`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{combo_map, ComboMap};
+///
+/// let map: ComboMap<&str, i32, 4> = combo_map! {
+///     "one" => 1,
+///     "two" => 2,
+/// };
+///
+/// assert_eq!(map.get("one"), Some(&1));
+/// assert_eq!(map.len(), 2);
+///
+/// let empty: ComboMap<&str, i32, 4> = combo_map! {};
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! combo_map {
+    () => (
+        $crate::ComboMap::new()
+    );
+    ($($key:expr => $val:expr),+ $(,)?) => ({
+        let mut map = $crate::ComboMap::new();
+        $(map.insert($key, $val);)+
+        map
+    });
+}
+
+/// A map that stores up to `N` entries on the stack, and moves to the heap for more.
+///
+/// While all entries fit on the stack, they are kept in insertion order and found with a linear scan.
+/// Once the map spills to the heap, the entries are kept sorted by key and found with a binary search.
+///
+/// Create a new [`ComboMap`] using the [`combo_map!`] macro.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{combo_map, ComboMap};
+///
+/// const EMPTY: ComboMap<&str, i32, 2> = combo_map! {};
+///
+/// let mut map = EMPTY;
+/// map.insert("b", 2);
+/// map.insert("a", 1);
+/// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"b", &"a"]);
+///
+/// // Spill to the heap, sorting the entries
+/// map.insert("c", 3);
+/// assert!(map.spilled());
+/// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"a", &"b", &"c"]);
+///
+/// *map.entry("a").or_insert(0) += 10;
+/// assert_eq!(map["a"], 11);
+/// ```
+pub struct ComboMap<K, V, const N: usize> {
+    entries: ComboVec<(K, V), N>,
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for ComboMap<K, V, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K: Ord, V: PartialEq, const N: usize> PartialEq for ComboMap<K, V, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Ord, V: Eq, const N: usize> Eq for ComboMap<K, V, N> {}
+
+impl<K, V, const N: usize> Default for ComboMap<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const N: usize> ComboMap<K, V, N> {
+    /// Create a new, empty [`ComboMap`] with the ability for `N` entries to be stored on the stack.
+    ///
+    /// This is used by the [`combo_map!`] macro.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let map = ComboMap::<&str, i32, 4>::new();
+    /// assert!(map.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: ComboVec::new(),
+        }
+    }
+
+    /// How many entries are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let map: ComboMap<i32, i32, 1> = combo_map! { 1 => 1, 2 => 4 };
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if there are no entries.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let map = ComboMap::<i32, i32, 1>::new();
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether or not there are any entries stored on the heap instead of the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, i32, 1> = combo_map! { 1 => 1 };
+    /// assert!(!map.spilled());
+    /// map.insert(2, 4);
+    /// assert!(map.spilled());
+    /// ```
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.entries.spilled()
+    }
+
+    /// Remove all entries.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, i32, 1> = combo_map! { 1 => 1, 2 => 4 };
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Retains only the entries specified by the predicate.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, i32, 4> = combo_map! { 1 => 1, 2 => 4, 3 => 9 };
+    /// map.retain(|k, v| {
+    ///     *v += 1;
+    ///     k % 2 == 1
+    /// });
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &2), (&3, &10)]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.entries.retain_mut(|(k, v)| f(k, v));
+    }
+
+    /// Get an iterator over the entries.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let map: ComboMap<i32, i32, 2> = combo_map! { 1 => 1, 2 => 4 };
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &1), (&2, &4)]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Get an iterator over the entries, with mutable references to the values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, i32, 2> = combo_map! { 1 => 1, 2 => 4 };
+    /// map.iter_mut().for_each(|(_, v)| *v += 1);
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &2), (&2, &5)]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> + '_ {
+        self.entries.iter_mut().map(|(k, v)| (&*k, v))
+    }
+
+    /// Get an iterator over the keys.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let map: ComboMap<i32, i32, 2> = combo_map! { 1 => 1, 2 => 4 };
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// Get an iterator over the values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let map: ComboMap<i32, i32, 2> = combo_map! { 1 => 1, 2 => 4 };
+    /// assert_eq!(map.values().collect::<Vec<_>>(), vec![&1, &4]);
+    /// ```
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    /// Get an iterator over mutable references to the values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, i32, 2> = combo_map! { 1 => 1, 2 => 4 };
+    /// map.values_mut().for_each(|v| *v *= 2);
+    /// assert_eq!(map.values().collect::<Vec<_>>(), vec![&2, &8]);
+    /// ```
+    #[inline]
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.entries.iter_mut().map(|(_, v)| v)
+    }
+}
+
+impl<K: Ord, V, const N: usize> ComboMap<K, V, N> {
+    // Find the index of the key with a linear scan while on the stack, or a binary search once spilled.
+    // If the key isn't found, the index where it would be inserted is returned instead.
+    fn find<Q: ?Sized + Ord>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+    {
+        if self.entries.len() <= N {
+            self.entries
+                .iter()
+                .position(|(k, _)| k.borrow() == key)
+                .ok_or(self.entries.len())
+        } else {
+            self.binary_search(key)
+        }
+    }
+
+    fn binary_search<Q: ?Sized + Ord>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+    {
+        let (mut low, mut high) = (0, self.entries.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.entries[mid].0.borrow().cmp(key) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
+    // Insert a key that isn't in the map yet, returning the index it was stored at
+    fn insert_new(&mut self, key: K, val: V) -> usize {
+        let len = self.entries.len();
+        if len < N {
+            self.entries.push((key, val));
+            return len;
+        }
+
+        if len == N {
+            // about to spill to the heap, so the entries need to be sorted
+            let mut entries = mem::replace(&mut self.entries, ComboVec::new()).into_vec();
+            entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+            self.entries = entries.into_iter().collect();
+        }
+
+        let (Ok(idx) | Err(idx)) = self.binary_search(&key);
+        self.entries.insert(idx, (key, val));
+        idx
+    }
+
+    /// Insert a key-value pair into the map.
+    ///
+    /// If the map already had the key, the value is updated and the old value is returned.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let mut map = ComboMap::<i32, &str, 2>::new();
+    /// assert_eq!(map.insert(1, "a"), None);
+    /// assert_eq!(map.insert(1, "b"), Some("a"));
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    pub fn insert(&mut self, key: K, val: V) -> Option<V> {
+        if let Ok(idx) = self.find(&key) {
+            Some(mem::replace(&mut self.entries[idx].1, val))
+        } else {
+            self.insert_new(key, val);
+            None
+        }
+    }
+
+    /// Get a reference to the value of the key, returning `None` if the key isn't in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let map: ComboMap<i32, &str, 2> = combo_map! { 1 => "a" };
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).ok().map(|idx| &self.entries[idx].1)
+    }
+
+    /// Get a mutable reference to the value of the key, returning `None` if the key isn't in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, &str, 2> = combo_map! { 1 => "a" };
+    /// if let Some(x) = map.get_mut(&1) {
+    ///     *x = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).ok().map(|idx| &mut self.entries[idx].1)
+    }
+
+    /// Check if the map contains the key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let map: ComboMap<i32, &str, 2> = combo_map! { 1 => "a" };
+    /// assert!(map.contains_key(&1));
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).is_ok()
+    }
+
+    /// Remove the key from the map, returning its value if the key was in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, &str, 2> = combo_map! { 1 => "a" };
+    /// assert_eq!(map.remove(&1), Some("a"));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    #[inline]
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Remove the key from the map, returning the stored key and its value if the key was in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, &str, 2> = combo_map! { 1 => "a" };
+    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
+    /// assert_eq!(map.remove_entry(&1), None);
+    /// ```
+    #[inline]
+    pub fn remove_entry<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).ok().map(|idx| self.entries.remove(idx))
+    }
+
+    /// Get the entry of the key for in-place manipulation.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let mut letters = ComboMap::<char, u32, 4>::new();
+    /// for ch in "a short treatise on fungi".chars() {
+    ///     *letters.entry(ch).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(letters[&'s'], 2);
+    /// assert_eq!(letters[&'t'], 3);
+    /// assert_eq!(letters.get(&'y'), None);
+    /// ```
+    #[inline]
+    pub fn entry(&mut self, key: K) -> ComboMapEntry<'_, K, V, N> {
+        match self.find(&key) {
+            Ok(idx) => ComboMapEntry::Occupied(ComboMapOccupiedEntry { map: self, idx }),
+            Err(_) => ComboMapEntry::Vacant(ComboMapVacantEntry { map: self, key }),
+        }
+    }
+
+    /// Insert all the key-value pairs from the given iterator.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_map, ComboMap};
+    ///
+    /// let mut map: ComboMap<i32, &str, 2> = combo_map! { 1 => "a" };
+    /// map.extend([(2, "b"), (1, "c")]);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map[&1], "c");
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
+/// A view into a single entry of a [`ComboMap`], which may either be vacant or occupied.
+///
+/// Created by [`ComboMap::entry`].
+pub enum ComboMapEntry<'a, K, V, const N: usize> {
+    /// The key is in the map.
+    Occupied(ComboMapOccupiedEntry<'a, K, V, N>),
+    /// The key isn't in the map.
+    Vacant(ComboMapVacantEntry<'a, K, V, N>),
+}
+
+/// A view into an occupied entry of a [`ComboMap`].
+pub struct ComboMapOccupiedEntry<'a, K, V, const N: usize> {
+    map: &'a mut ComboMap<K, V, N>,
+    idx: usize,
+}
+
+/// A view into a vacant entry of a [`ComboMap`].
+pub struct ComboMapVacantEntry<'a, K, V, const N: usize> {
+    map: &'a mut ComboMap<K, V, N>,
+    key: K,
+}
+
+impl<'a, K: Ord, V, const N: usize> ComboMapEntry<'a, K, V, N> {
+    /// Get the key of this entry.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let mut map = ComboMap::<&str, i32, 2>::new();
+    /// assert_eq!(map.entry("a").key(), &"a");
+    /// ```
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Insert `default` if the entry is vacant, and return a mutable reference to the value.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let mut map = ComboMap::<&str, i32, 2>::new();
+    /// *map.entry("a").or_insert(1) += 1;
+    /// *map.entry("a").or_insert(1) += 1;
+    /// assert_eq!(map["a"], 3);
+    /// ```
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of `f` if the entry is vacant, and return a mutable reference to the value.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let mut map = ComboMap::<&str, String, 2>::new();
+    /// map.entry("a").or_insert_with(|| "hello".to_string()).push('!');
+    /// assert_eq!(map["a"], "hello!");
+    /// ```
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, f: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// Insert the result of calling `f` with the key if the entry is vacant,
+    /// and return a mutable reference to the value.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let mut map = ComboMap::<&str, usize, 2>::new();
+    /// map.entry("hello").or_insert_with_key(|key| key.len());
+    /// assert_eq!(map["hello"], 5);
+    /// ```
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, f: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => {
+                let val = f(entry.key());
+                entry.insert(val)
+            }
+        }
+    }
+
+    /// Modify the value in place if the entry is occupied.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let mut map = ComboMap::<&str, i32, 2>::new();
+    /// map.entry("a").and_modify(|v| *v += 1).or_insert(1);
+    /// map.entry("a").and_modify(|v| *v += 1).or_insert(1);
+    /// assert_eq!(map["a"], 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, K: Ord, V: Default, const N: usize> ComboMapEntry<'a, K, V, N> {
+    /// Insert the default value if the entry is vacant, and return a mutable reference to the value.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboMap;
+    ///
+    /// let mut map = ComboMap::<&str, i32, 2>::new();
+    /// *map.entry("a").or_default() += 1;
+    /// assert_eq!(map["a"], 1);
+    /// ```
+    #[inline]
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> ComboMapOccupiedEntry<'a, K, V, N> {
+    /// Get the key of this entry.
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.map.entries[self.idx].0
+    }
+
+    /// Get a reference to the value of this entry.
+    #[must_use]
+    #[inline]
+    pub fn get(&self) -> &V {
+        &self.map.entries[self.idx].1
+    }
+
+    /// Get a mutable reference to the value of this entry.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.entries[self.idx].1
+    }
+
+    /// Convert this entry into a mutable reference to its value, with the lifetime of the map.
+    #[must_use]
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.entries[self.idx].1
+    }
+
+    /// Replace the value of this entry, returning the old value.
+    #[inline]
+    pub fn insert(&mut self, val: V) -> V {
+        mem::replace(self.get_mut(), val)
+    }
+
+    /// Remove this entry from the map, returning its value.
+    #[must_use]
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Remove this entry from the map, returning the stored key and its value.
+    #[must_use]
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.entries.remove(self.idx)
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> ComboMapVacantEntry<'a, K, V, N> {
+    /// Get the key of this entry.
+    #[inline]
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take ownership of the key.
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Insert the value into the map, returning a mutable reference to it.
+    #[inline]
+    pub fn insert(self, val: V) -> &'a mut V {
+        let idx = self.map.insert_new(self.key, val);
+        &mut self.map.entries[idx].1
+    }
+}
+
+impl<K: Ord + Borrow<Q>, V, Q: ?Sized + Ord, const N: usize> ops::Index<&Q> for ComboMap<K, V, N> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for ComboMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = <ComboVec<(K, V), N> as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<K: Ord, V, const N: usize> FromIterator<(K, V)> for ComboMap<K, V, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Debug, V: Debug, const N: usize> Debug for ComboMap<K, V, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,467 @@
+use crate::{ComboMap, ComboMapEntry};
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    iter::Map,
+};
+
+/// Easy creation of a new [`ComboSet`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{combo_set, ComboSet};
+///
+/// let set: ComboSet<i32, 4> = combo_set![1, 2, 3, 2];
+/// assert_eq!(set.len(), 3);
+/// assert!(set.contains(&2));
+///
+/// let empty: ComboSet<i32, 4> = combo_set![];
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! combo_set {
+    () => (
+        $crate::ComboSet::new()
+    );
+    ($($x:expr),+ $(,)?) => ({
+        let mut set = $crate::ComboSet::new();
+        $(set.insert($x);)+
+        set
+    });
+}
+
+/// A set that stores up to `N` values on the stack, and moves to the heap for more.
+///
+/// While all values fit on the stack, they are kept in insertion order and found with a linear scan.
+/// Once the set spills to the heap, the values are kept sorted and found with a binary search.
+///
+/// Unlike [`ComboVec`](crate::ComboVec), two sets are equal if they hold the same values in any order.
+///
+/// Create a new [`ComboSet`] using the [`combo_set!`] macro.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{combo_set, ComboSet};
+///
+/// const EMPTY: ComboSet<i32, 2> = combo_set![];
+///
+/// let mut set = EMPTY;
+/// assert!(set.insert(2));
+/// assert!(set.insert(1));
+/// assert!(!set.insert(2));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&2, &1]);
+///
+/// // Spill to the heap, sorting the values
+/// set.insert(3);
+/// assert!(set.spilled());
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+///
+/// let other: ComboSet<i32, 2> = combo_set![3, 2, 1];
+/// assert_eq!(set, other);
+/// ```
+pub struct ComboSet<T, const N: usize> {
+    map: ComboMap<T, (), N>,
+}
+
+impl<T: Clone, const N: usize> Clone for ComboSet<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: Ord, const N: usize> PartialEq for ComboSet<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Ord, const N: usize> Eq for ComboSet<T, N> {}
+
+impl<T, const N: usize> Default for ComboSet<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ComboSet<T, N> {
+    /// Create a new, empty [`ComboSet`] with the ability for `N` values to be stored on the stack.
+    ///
+    /// This is used by the [`combo_set!`] macro.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSet;
+    ///
+    /// let set = ComboSet::<i32, 4>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            map: ComboMap::new(),
+        }
+    }
+
+    /// How many values are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let set: ComboSet<i32, 1> = combo_set![1, 2];
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check if there are no values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSet;
+    ///
+    /// let set = ComboSet::<i32, 1>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Whether or not there are any values stored on the heap instead of the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let mut set: ComboSet<i32, 1> = combo_set![1];
+    /// assert!(!set.spilled());
+    /// set.insert(2);
+    /// assert!(set.spilled());
+    /// ```
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.map.spilled()
+    }
+
+    /// Remove all values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let mut set: ComboSet<i32, 1> = combo_set![1, 2];
+    /// set.clear();
+    /// assert!(set.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Retains only the values specified by the predicate.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let mut set: ComboSet<i32, 2> = combo_set![1, 2, 3, 4];
+    /// set.retain(|x| x % 2 == 0);
+    /// assert_eq!(set, combo_set![2, 4]);
+    /// assert!(!set.spilled());
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.map.retain(|x, ()| f(x));
+    }
+
+    /// Get an iterator over the values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let set: ComboSet<i32, 4> = combo_set![3, 1, 2];
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&3, &1, &2]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.map.keys()
+    }
+}
+
+impl<T: Ord, const N: usize> ComboSet<T, N> {
+    /// Add a value to the set, returning whether it was newly added.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSet;
+    ///
+    /// let mut set = ComboSet::<i32, 2>::new();
+    /// assert!(set.insert(1));
+    /// assert!(!set.insert(1));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, val: T) -> bool {
+        match self.map.entry(val) {
+            ComboMapEntry::Occupied(_) => false,
+            ComboMapEntry::Vacant(entry) => {
+                entry.insert(());
+                true
+            }
+        }
+    }
+
+    /// Check if the set contains the value.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let set: ComboSet<&str, 2> = combo_set!["a", "b", "c"];
+    /// assert!(set.contains("a"));
+    /// assert!(!set.contains("d"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains<Q: ?Sized + Ord>(&self, val: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.contains_key(val)
+    }
+
+    /// Remove a value from the set, returning whether it was present.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let mut set: ComboSet<i32, 2> = combo_set![1, 2];
+    /// assert!(set.remove(&1));
+    /// assert!(!set.remove(&1));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[inline]
+    pub fn remove<Q: ?Sized + Ord>(&mut self, val: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove(val).is_some()
+    }
+
+    /// Remove a value from the set and return it, or `None` if it wasn't present.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let mut set: ComboSet<String, 2> = combo_set![String::from("a")];
+    /// assert_eq!(set.take("a"), Some(String::from("a")));
+    /// assert_eq!(set.take("a"), None);
+    /// ```
+    #[inline]
+    pub fn take<Q: ?Sized + Ord>(&mut self, val: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove_entry(val).map(|(x, ())| x)
+    }
+
+    /// Add all values of an iterator to the set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSet;
+    ///
+    /// let mut set = ComboSet::<i32, 2>::new();
+    /// set.extend([1, 2, 1, 3]);
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| {
+            self.insert(x);
+        });
+    }
+
+    /// Get an iterator over the values that are in `self` or `other`, without duplicates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let a: ComboSet<i32, 4> = combo_set![1, 2, 3];
+    /// let b: ComboSet<i32, 2> = combo_set![2, 3, 4];
+    /// assert_eq!(a.union(&b).collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// ```
+    #[inline]
+    pub fn union<'a, const M: usize>(
+        &'a self,
+        other: &'a ComboSet<T, M>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().chain(other.difference(self))
+    }
+
+    /// Get an iterator over the values that are in both `self` and `other`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let a: ComboSet<i32, 4> = combo_set![1, 2, 3];
+    /// let b: ComboSet<i32, 2> = combo_set![2, 3, 4];
+    /// assert_eq!(a.intersection(&b).collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    #[inline]
+    pub fn intersection<'a, const M: usize>(
+        &'a self,
+        other: &'a ComboSet<T, M>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().filter(|x| other.contains(*x))
+    }
+
+    /// Get an iterator over the values that are in `self` but not in `other`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let a: ComboSet<i32, 4> = combo_set![1, 2, 3];
+    /// let b: ComboSet<i32, 2> = combo_set![2, 3, 4];
+    /// assert_eq!(a.difference(&b).collect::<Vec<_>>(), vec![&1]);
+    /// ```
+    #[inline]
+    pub fn difference<'a, const M: usize>(
+        &'a self,
+        other: &'a ComboSet<T, M>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().filter(|x| !other.contains(*x))
+    }
+
+    /// Get an iterator over the values that are in `self` or `other`, but not in both.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let a: ComboSet<i32, 4> = combo_set![1, 2, 3];
+    /// let b: ComboSet<i32, 2> = combo_set![2, 3, 4];
+    /// assert_eq!(a.symmetric_difference(&b).collect::<Vec<_>>(), vec![&1, &4]);
+    /// ```
+    #[inline]
+    pub fn symmetric_difference<'a, const M: usize>(
+        &'a self,
+        other: &'a ComboSet<T, M>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.difference(other).chain(other.difference(self))
+    }
+
+    /// Check if every value in `self` is also in `other`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let a: ComboSet<i32, 4> = combo_set![1, 2];
+    /// let b: ComboSet<i32, 2> = combo_set![1, 2, 3];
+    /// assert!(a.is_subset(&b));
+    /// assert!(!b.is_subset(&a));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_subset<const M: usize>(&self, other: &ComboSet<T, M>) -> bool {
+        self.len() <= other.len() && self.iter().all(|x| other.contains(x))
+    }
+
+    /// Check if every value in `other` is also in `self`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let a: ComboSet<i32, 4> = combo_set![1, 2, 3];
+    /// let b: ComboSet<i32, 2> = combo_set![1, 2];
+    /// assert!(a.is_superset(&b));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_superset<const M: usize>(&self, other: &ComboSet<T, M>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Check if `self` and `other` have no values in common.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_set, ComboSet};
+    ///
+    /// let a: ComboSet<i32, 4> = combo_set![1, 2];
+    /// let b: ComboSet<i32, 2> = combo_set![3, 4];
+    /// assert!(a.is_disjoint(&b));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_disjoint<const M: usize>(&self, other: &ComboSet<T, M>) -> bool {
+        self.intersection(other).next().is_none()
+    }
+}
+
+type IntoIterValue<T> = fn((T, ())) -> T;
+
+impl<T, const N: usize> IntoIterator for ComboSet<T, N> {
+    type Item = T;
+    type IntoIter = Map<<ComboMap<T, (), N> as IntoIterator>::IntoIter, IntoIterValue<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let value: IntoIterValue<T> = |(x, ())| x;
+        self.map.into_iter().map(value)
+    }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for ComboSet<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ComboSet<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,462 @@
+use crate::ReSlab;
+use alloc::vec::{IntoIter as VecIter, Vec};
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    iter::{Chain, Enumerate, FilterMap},
+    ops,
+};
+
+/// A slab that hands out stable keys for the elements it stores,
+/// keeping the first `N` slots on the stack and moving to the heap for more.
+///
+/// Removing an element frees its key, which will be reused by a later insert.
+/// The keys of all other elements are unchanged.
+/// Free slots on the stack are always used before those on the heap.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::ComboSlab;
+///
+/// const EMPTY: ComboSlab<&str, 1> = ComboSlab::new();
+///
+/// let mut slab = EMPTY;
+/// let hello = slab.insert("hello");
+/// // This is stored on the heap
+/// let world = slab.insert("world");
+/// assert!(slab.spilled());
+///
+/// assert_eq!(slab.remove(hello), Some("hello"));
+/// assert_eq!(slab[world], "world");
+///
+/// // The freed key is reused
+/// assert_eq!(slab.insert("again"), hello);
+/// ```
+pub struct ComboSlab<T, const N: usize> {
+    slab: ReSlab<T, N>,
+    vec: Vec<Option<T>>,
+    heap_len: usize,
+    // every slot on the heap before this index is occupied
+    heap_next_free: usize,
+}
+
+impl<T: Clone, const N: usize> Clone for ComboSlab<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slab: self.slab.clone(),
+            vec: self.vec.clone(),
+            heap_len: self.heap_len,
+            heap_next_free: self.heap_next_free,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ComboSlab<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ComboSlab<T, N> {
+    /// Create a new, empty [`ComboSlab`] with room for `N` elements on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let slab = ComboSlab::<i32, 3>::new();
+    /// assert!(slab.is_empty());
+    /// assert_eq!(slab.stack_capacity(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            slab: ReSlab::new(),
+            vec: Vec::new(),
+            heap_len: 0,
+            heap_next_free: 0,
+        }
+    }
+
+    /// Get the key that the next call to [`ComboSlab::insert`] will return.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// assert_eq!(slab.vacant_key(), 0);
+    /// slab.insert(1);
+    /// assert_eq!(slab.vacant_key(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn vacant_key(&self) -> usize {
+        self.slab
+            .vacant_key()
+            .unwrap_or_else(|| N + self.heap_vacant_key())
+    }
+
+    // The index of the first free slot on the heap, which may be one past the end
+    fn heap_vacant_key(&self) -> usize {
+        (self.heap_next_free..self.vec.len())
+            .find(|&idx| self.vec[idx].is_none())
+            .unwrap_or(self.vec.len())
+    }
+
+    /// Store an element, returning the key it can be retrieved with.
+    ///
+    /// If there are no free slots on the stack, the element will be stored on the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// let first = slab.insert(5);
+    /// let second = slab.insert(6);
+    /// assert_eq!(slab.get(first), Some(&5));
+    /// assert_eq!(slab.get(second), Some(&6));
+    /// ```
+    pub fn insert(&mut self, val: T) -> usize {
+        let val = match self.slab.try_insert(val) {
+            Ok(key) => return key,
+            Err(err) => err.into_inner(),
+        };
+
+        let idx = self.heap_vacant_key();
+        if idx == self.vec.len() {
+            self.vec.push(Some(val));
+        } else {
+            self.vec[idx] = Some(val);
+        }
+
+        self.heap_len += 1;
+        self.heap_next_free = idx + 1;
+
+        N + idx
+    }
+
+    /// Remove and return the element with the given key, or `None` if there is no such element.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// let key = slab.insert(6);
+    /// assert_eq!(slab.remove(key), Some(6));
+    /// assert_eq!(slab.remove(key), None);
+    /// assert!(!slab.spilled());
+    /// ```
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        if key < N {
+            return self.slab.remove(key);
+        }
+
+        let idx = key - N;
+        let val = self.vec.get_mut(idx)?.take()?;
+        self.heap_len -= 1;
+        self.heap_next_free = self.heap_next_free.min(idx);
+
+        // free slots at the end of the heap don't need to be kept around
+        while matches!(self.vec.last(), Some(None)) {
+            self.vec.pop();
+        }
+
+        Some(val)
+    }
+
+    /// Check if there is an element with the given key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// let key = slab.insert(5);
+    /// assert!(slab.contains(key));
+    /// assert!(!slab.contains(key + 1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Get the element with the given key as a reference, returning `None` if there is no such element.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// let key = slab.insert(6);
+    /// assert_eq!(slab.get(key), Some(&6));
+    /// assert_eq!(slab.get(key + 1), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, key: usize) -> Option<&T> {
+        if key < N {
+            self.slab.get(key)
+        } else {
+            self.vec.get(key - N).and_then(|slot| slot.as_ref())
+        }
+    }
+
+    /// Get the element with the given key as a mutable reference, returning `None` if there is no such element.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// let key = slab.insert(6);
+    /// *slab.get_mut(key).unwrap() = 7;
+    /// assert_eq!(slab[key], 7);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        if key < N {
+            self.slab.get_mut(key)
+        } else {
+            self.vec.get_mut(key - N).and_then(|slot| slot.as_mut())
+        }
+    }
+
+    /// Whether or not there are any elements stored on the heap instead of the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// assert!(!slab.spilled());
+    /// slab.insert(6);
+    /// assert!(slab.spilled());
+    /// ```
+    #[inline]
+    pub const fn spilled(&self) -> bool {
+        self.heap_len > 0
+    }
+
+    /// How many elements are currently stored on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// slab.insert(6);
+    /// assert_eq!(slab.stack_len(), 1);
+    /// ```
+    #[inline]
+    pub const fn stack_len(&self) -> usize {
+        self.slab.len()
+    }
+
+    /// How many elements are currently stored on the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// slab.insert(6);
+    /// assert_eq!(slab.heap_len(), 1);
+    /// ```
+    #[inline]
+    pub const fn heap_len(&self) -> usize {
+        self.heap_len
+    }
+
+    /// How many elements are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// slab.insert(6);
+    /// assert_eq!(slab.len(), 2);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.stack_len() + self.heap_len()
+    }
+
+    /// Check if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let slab = ComboSlab::<i32, 1>::new();
+    /// assert!(slab.is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How many elements can be stored on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let slab = ComboSlab::<i32, 3>::new();
+    /// assert_eq!(slab.stack_capacity(), 3);
+    /// ```
+    #[inline]
+    pub const fn stack_capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove all elements, freeing every key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// slab.insert(6);
+    /// slab.clear();
+    /// assert!(slab.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.slab.clear();
+        self.vec.clear();
+        self.heap_len = 0;
+        self.heap_next_free = 0;
+    }
+
+    /// Get an iterator over the stored elements and their keys, in order of key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// let key = slab.insert(6);
+    /// slab.insert(7);
+    /// slab.remove(key);
+    ///
+    /// assert_eq!(slab.iter().collect::<Vec<_>>(), vec![(0, &5), (2, &7)]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.slab.iter().chain(
+            self.vec
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, slot)| slot.as_ref().map(|val| (N + idx, val))),
+        )
+    }
+
+    /// Get an iterator over the stored elements and their keys, returning mutable references.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboSlab;
+    ///
+    /// let mut slab = ComboSlab::<i32, 1>::new();
+    /// slab.insert(5);
+    /// slab.insert(6);
+    ///
+    /// for (key, val) in slab.iter_mut() {
+    ///     *val += key as i32;
+    /// }
+    ///
+    /// assert_eq!(slab.iter().collect::<Vec<_>>(), vec![(0, &5), (1, &7)]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> + '_ {
+        self.slab.iter_mut().chain(
+            self.vec
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(idx, slot)| slot.as_mut().map(|val| (N + idx, val))),
+        )
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for ComboSlab<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, key: usize) -> &Self::Output {
+        if key < N {
+            &self.slab[key]
+        } else {
+            self.vec[key - N].as_ref().unwrap()
+        }
+    }
+}
+
+impl<T, const N: usize> ops::IndexMut<usize> for ComboSlab<T, N> {
+    #[inline]
+    fn index_mut(&mut self, key: usize) -> &mut Self::Output {
+        if key < N {
+            &mut self.slab[key]
+        } else {
+            self.vec[key - N].as_mut().unwrap()
+        }
+    }
+}
+
+type IntoIterHeapSlot<T> = fn((usize, Option<T>)) -> Option<(usize, T)>;
+
+impl<T, const N: usize> IntoIterator for ComboSlab<T, N> {
+    type Item = (usize, T);
+    type IntoIter = Chain<
+        <ReSlab<T, N> as IntoIterator>::IntoIter,
+        FilterMap<Enumerate<VecIter<Option<T>>>, IntoIterHeapSlot<T>>,
+    >;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let heap_slot: IntoIterHeapSlot<T> = |(idx, slot)| slot.map(|val| (N + idx, val));
+        self.slab
+            .into_iter()
+            .chain(self.vec.into_iter().enumerate().filter_map(heap_slot))
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ComboSlab<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,416 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter,
+    ops::{self, Bound, RangeBounds},
+};
+
+// Resolve any kind of range into a `start..end` pair, checked against `len`.
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "slice index starts at {start} but ends at {end}"
+    );
+    assert!(
+        end <= len,
+        "range end index {end} out of range for slice of length {len}"
+    );
+
+    (start, end)
+}
+
+/// A borrowed, contiguous view into a [`ComboVec`](crate::ComboVec) or a [`BoxedComboVec`](crate::BoxedComboVec).
+///
+/// Because the elements of a [`ComboVec`](crate::ComboVec) may be split between the stack and the heap,
+/// a view can't be represented as a regular slice.
+/// [`ComboSlice`] works the same regardless of where its elements live.
+///
+/// Create a new [`ComboSlice`] using [`ComboVec::slice`](crate::ComboVec::slice),
+/// or from one of the iterators such as [`ComboVec::chunks`](crate::ComboVec::chunks) or [`ComboVec::windows`](crate::ComboVec::windows).
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::combo_vec;
+///
+/// let mut x = combo_vec![1, 2, 3];
+/// x.extend([4, 5]);
+///
+/// // This view spans both the stack and the heap
+/// let view = x.slice(1..4);
+/// assert_eq!(view.len(), 3);
+/// assert_eq!(view[0], 2);
+/// assert_eq!(view.to_vec(), vec![2, 3, 4]);
+/// ```
+pub struct ComboSlice<'a, T, const N: usize> {
+    stack: &'a [Option<T>],
+    heap: &'a [T],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> Clone for ComboSlice<'_, T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for ComboSlice<'_, T, N> {}
+
+impl<T: PartialEq, const N: usize> PartialEq for ComboSlice<'_, T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq + Eq, const N: usize> Eq for ComboSlice<'_, T, N> {}
+
+impl<T: Hash, const N: usize> Hash for ComboSlice<'_, T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter().for_each(|x| x.hash(state));
+    }
+}
+
+impl<'a, T, const N: usize> ComboSlice<'a, T, N> {
+    // `stack` holds the occupied stack slots, and `heap` the elements after them.
+    //
+    // ## Panics
+    //
+    // Panics if the range is out of bounds.
+    #[inline]
+    pub(crate) fn new<R: RangeBounds<usize>>(
+        stack: &'a [Option<T>],
+        heap: &'a [T],
+        range: R,
+    ) -> Self {
+        let (start, end) = resolve_range(range, stack.len() + heap.len());
+
+        Self {
+            stack,
+            heap,
+            start,
+            end,
+        }
+    }
+
+    // Get an element by its index in the whole collection, rather than in the view.
+    #[inline]
+    fn at(&self, idx: usize) -> Option<&'a T> {
+        if idx < N {
+            self.stack.get(idx)?.as_ref()
+        } else {
+            self.heap.get(idx - N)
+        }
+    }
+
+    // Narrow the view, with `start` and `end` relative to this view.
+    #[inline]
+    const fn sub(self, start: usize, end: usize) -> Self {
+        Self {
+            start: self.start + start,
+            end: self.start + end,
+            ..self
+        }
+    }
+
+    /// How many elements are in the view.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3];
+    /// assert_eq!(x.slice(1..).len(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Check if there are no elements in the view.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3];
+    /// assert!(x.slice(3..).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Get any element from the view as a reference, returning `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3];
+    /// let view = x.slice(1..);
+    /// assert_eq!(view.get(0), Some(&2));
+    /// assert_eq!(view.get(1), Some(&3));
+    /// assert_eq!(view.get(2), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&'a T> {
+        if idx < self.len() {
+            self.at(self.start + idx)
+        } else {
+            None
+        }
+    }
+
+    /// Get the first element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3];
+    /// assert_eq!(x.slice(1..).first(), Some(&2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn first(&self) -> Option<&'a T> {
+        self.get(0)
+    }
+
+    /// Get the last element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3];
+    /// assert_eq!(x.slice(..2).last(), Some(&2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn last(&self) -> Option<&'a T> {
+        self.len().checked_sub(1).and_then(|idx| self.get(idx))
+    }
+
+    /// Get a narrower view into this view.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3, 4, 5];
+    /// let view = x.slice(1..);
+    /// assert_eq!(view.slice(1..3).to_vec(), vec![3, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let (start, end) = resolve_range(range, self.len());
+        self.sub(start, end)
+    }
+
+    /// Get an iterator over the elements of the view.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3];
+    /// assert_eq!(x.slice(1..).iter().collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + 'a {
+        let view = *self;
+        (0..self.len()).map(move |idx| {
+            view.get(idx)
+                .unwrap_or_else(|| unreachable!("index is within the view"))
+        })
+    }
+
+    /// Get this [`ComboSlice`] represented as a [`Vec`], borrowing data instead of moving it.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3];
+    /// assert_eq!(x.slice(1..).ref_vec(), vec![&2, &3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn ref_vec(&self) -> Vec<&'a T> {
+        self.iter().collect()
+    }
+
+    // The iterators below back the methods of the same name on the owning collections,
+    // which document them.
+
+    #[inline]
+    pub(crate) fn chunks(self, chunk_size: usize) -> impl Iterator<Item = Self> + 'a {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        let len = self.len();
+        (0..len)
+            .step_by(chunk_size)
+            .map(move |start| self.sub(start, len.min(start + chunk_size)))
+    }
+
+    #[inline]
+    pub(crate) fn chunks_exact(self, chunk_size: usize) -> impl Iterator<Item = Self> + 'a {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        (0..self.len() / chunk_size).map(move |i| {
+            let start = i * chunk_size;
+            self.sub(start, start + chunk_size)
+        })
+    }
+
+    #[inline]
+    pub(crate) fn rchunks(self, chunk_size: usize) -> impl Iterator<Item = Self> + 'a {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        let len = self.len();
+        (0..len.div_ceil(chunk_size)).map(move |i| {
+            let end = len - i * chunk_size;
+            self.sub(end.saturating_sub(chunk_size), end)
+        })
+    }
+
+    #[inline]
+    pub(crate) fn windows(self, size: usize) -> impl Iterator<Item = Self> + 'a {
+        assert!(size != 0, "window size must be non-zero");
+
+        (0..(self.len() + 1).saturating_sub(size)).map(move |start| self.sub(start, start + size))
+    }
+
+    #[inline]
+    pub(crate) fn split<F: FnMut(&T) -> bool + 'a>(
+        self,
+        mut pred: F,
+    ) -> impl Iterator<Item = Self> + 'a {
+        let len = self.len();
+        let mut start = 0;
+        let mut finished = false;
+
+        iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+
+            if let Some(idx) = (start..len).find(|&idx| pred(&self[idx])) {
+                let group = self.sub(start, idx);
+                start = idx + 1;
+                Some(group)
+            } else {
+                finished = true;
+                Some(self.sub(start, len))
+            }
+        })
+    }
+
+    #[inline]
+    pub(crate) fn chunk_by<F: FnMut(&T, &T) -> bool + 'a>(
+        self,
+        mut pred: F,
+    ) -> impl Iterator<Item = Self> + 'a {
+        let len = self.len();
+        let mut start = 0;
+
+        iter::from_fn(move || {
+            if start >= len {
+                return None;
+            }
+
+            let mut end = start + 1;
+            while end < len && pred(&self[end - 1], &self[end]) {
+                end += 1;
+            }
+
+            let group = self.sub(start, end);
+            start = end;
+            Some(group)
+        })
+    }
+}
+
+impl<T: Clone, const N: usize> ComboSlice<'_, T, N> {
+    /// Get this [`ComboSlice`] represented as a [`Vec`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let x = combo_vec![1, 2, 3];
+    /// assert_eq!(x.slice(..2).to_vec(), vec![1, 2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for ComboSlice<'_, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &Self::Output {
+        self.get(idx).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is {} but the index is {idx}",
+                self.len()
+            )
+        })
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ComboSlice<'_, T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ComboSlice")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("items", &self.ref_vec())
+            .finish()
+    }
+}
+
+impl<T: Debug, const N: usize> Display for ComboSlice<'_, T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
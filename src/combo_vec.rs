@@ -1,4 +1,4 @@
-use crate::ReArr;
+use crate::{combo_slice::ComboSlice, ReArr};
 use alloc::{
     string::{String, ToString},
     vec::{IntoIter as VecIter, Vec},
@@ -9,7 +9,8 @@ use core::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
     iter::{Chain, Flatten},
-    ops,
+    mem,
+    ops::{self, RangeBounds},
 };
 
 /// Easy creation of a new [`ComboVec`].
@@ -79,8 +80,8 @@ macro_rules! combo_vec {
 /// my_combo_vec.extend([3, 4, 5]);
 /// ```
 pub struct ComboVec<T, const N: usize> {
-    arr: ReArr<T, N>,
-    vec: Vec<T>,
+    pub(crate) arr: ReArr<T, N>,
+    pub(crate) vec: Vec<T>,
 }
 
 impl<T: Clone, const N: usize> Clone for ComboVec<T, N> {
@@ -185,6 +186,23 @@ impl<T, const N: usize> ComboVec<T, N> {
         self.vec.reserve(additional);
     }
 
+    /// Create an empty [`ComboVec`] that spills into an existing heap buffer, keeping its capacity.
+    #[inline]
+    pub(crate) fn from_heap_buffer(mut vec: Vec<T>) -> Self {
+        vec.clear();
+        Self {
+            arr: ReArr::new(),
+            vec,
+        }
+    }
+
+    /// Remove all elements and take the heap buffer, leaving an unallocated one in its place.
+    #[inline]
+    pub(crate) fn take_heap_buffer(&mut self) -> Vec<T> {
+        self.clear();
+        mem::take(&mut self.vec)
+    }
+
     /// Create a [`ComboVec`] from a fixed size array.
     ///
     /// All slots must be populated with `Some` values until
@@ -229,6 +247,35 @@ impl<T, const N: usize> ComboVec<T, N> {
     /// ```
     #[inline]
     pub fn push(&mut self, val: T) {
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_push(self.len() + 1, N);
+
+        self.push_untracked(val);
+    }
+
+    /// Push an element to the end of the array, recording it in the given [`SpillStats`](crate::SpillStats)
+    /// instead of the global ones.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, SpillStats};
+    ///
+    /// static STATS: SpillStats = SpillStats::new();
+    ///
+    /// let mut my_combo_vec = combo_vec![1, 2, 3];
+    /// my_combo_vec.push_tracked(4, &STATS);
+    /// assert_eq!(STATS.snapshot().spills(), 1);
+    /// ```
+    #[cfg(feature = "stats")]
+    #[inline]
+    pub fn push_tracked(&mut self, val: T, stats: &crate::SpillStats) {
+        stats.record_push(self.len() + 1, N);
+        self.push_untracked(val);
+    }
+
+    #[inline]
+    fn push_untracked(&mut self, val: T) {
         if self.len() < N {
             self.arr.push(val);
         } else {
@@ -468,6 +515,90 @@ impl<T, const N: usize> ComboVec<T, N> {
         self.vec.clear();
     }
 
+    /// Retains only the elements specified by the predicate, preserving their order.
+    ///
+    /// Elements are moved from the heap back onto the stack as space frees up.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5, 6]);
+    /// x.retain(|x| x % 2 == 0);
+    /// assert_eq!(x.to_vec(), vec![2, 4, 6]);
+    /// assert!(!x.spilled());
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|x| f(x));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// Elements are moved from the heap back onto the stack as space frees up.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5, 6]);
+    /// x.retain_mut(|x| {
+    ///     *x += 1;
+    ///     *x % 2 == 0
+    /// });
+    /// assert_eq!(x.to_vec(), vec![2, 4, 6]);
+    /// assert!(!x.spilled());
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        self.arr.retain_mut(&mut f);
+        self.vec.retain_mut(f);
+        self.fill_stack_from_heap();
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    ///
+    /// If the stack is full, the last element on the stack is moved to the heap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.insert(1, 4);
+    /// assert_eq!(x.to_vec(), vec![1, 4, 2, 3]);
+    /// assert_eq!(x.heap_len(), 1);
+    /// x.insert(4, 5);
+    /// assert_eq!(x.to_vec(), vec![1, 4, 2, 3, 5]);
+    /// ```
+    pub fn insert(&mut self, index: usize, val: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if index >= N {
+            self.vec.insert(index - N, val);
+        } else {
+            if self.arr.len() == N {
+                if let Some(last) = self.arr.pop() {
+                    self.vec.insert(0, last);
+                }
+            }
+
+            self.arr.insert(index, val);
+        }
+    }
+
     /// Removes and returns the element at position with a valid index, shifting all elements after it to the left.
     ///
     /// ## Panics
@@ -539,6 +670,97 @@ impl<T, const N: usize> ComboVec<T, N> {
         }
     }
 
+    /// Swaps two elements in the `ComboVec`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5]);
+    /// x.swap(0, 4);
+    /// x.swap(1, 2);
+    /// assert_eq!(x.to_vec(), vec![5, 3, 2, 4, 1]);
+    /// ```
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        match (a < N, b < N) {
+            (true, true) => self.arr.swap(a, b),
+            (false, false) => self.vec.swap(a - N, b - N),
+            (true, false) => mem::swap(&mut self.arr[a], &mut self.vec[b - N]),
+            (false, true) => mem::swap(&mut self.arr[b], &mut self.vec[a - N]),
+        }
+    }
+
+    /// Removes all but the first of consecutive elements that resolve to the same key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![10, 20, 21];
+    /// x.extend([30, 20]);
+    /// x.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(x.to_vec(), vec![10, 20, 30, 20]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive elements that satisfy the given equality relation.
+    ///
+    /// The `same_bucket` function is passed the element being considered,
+    /// followed by the last element that was kept.
+    /// If it returns `true`, the element being considered is removed.
+    ///
+    /// Elements are moved from the heap back onto the stack as space frees up.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec!["foo", "bar", "Bar"];
+    /// x.extend(["baz", "bar"]);
+    /// x.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(x.to_vec(), vec!["foo", "bar", "baz", "bar"]);
+    /// assert_eq!(x.stack_len(), 3);
+    /// assert_eq!(x.heap_len(), 1);
+    /// ```
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        self.arr.dedup_by(&mut same_bucket);
+
+        // the first elements of the heap are compared against the last element kept on the stack
+        if let Some(last_kept) = self.arr.last_mut() {
+            let removed = self
+                .vec
+                .iter_mut()
+                .position(|val| !same_bucket(val, last_kept))
+                .unwrap_or(self.vec.len());
+            self.vec.drain(..removed);
+        }
+
+        self.vec.dedup_by(same_bucket);
+        self.fill_stack_from_heap();
+    }
+
+    // Move elements from the start of the heap into any free slots on the stack,
+    // restoring the invariant that the stack is full before the heap is used.
+    fn fill_stack_from_heap(&mut self) {
+        let free_slots = N - self.arr.len();
+        if free_slots != 0 && !self.vec.is_empty() {
+            self.arr
+                .extend(self.vec.drain(..free_slots.min(self.vec.len())));
+        }
+    }
+
     /// Get the first element, returning `None` if there are no elements.
     ///
     /// ## Examples
@@ -672,6 +894,9 @@ impl<T, const N: usize> ComboVec<T, N> {
 
     /// Extend this array with all the elements from the given iterator.
     ///
+    /// The stack is filled first, then the rest of the elements are moved onto the heap in one go.
+    /// The heap reserves room for the iterator's lower size bound up front, so spilling allocates at most once for exact-size iterators.
+    ///
     /// ## Examples
     ///
     /// ```rust
@@ -683,7 +908,202 @@ impl<T, const N: usize> ComboVec<T, N> {
     /// ```
     #[inline]
     pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        iter.into_iter().for_each(|x| self.push(x));
+        #[cfg(feature = "stats")]
+        let old_len = self.len();
+
+        self.extend_untracked(iter);
+
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_growth(old_len, self.len(), N);
+    }
+
+    // Extend without recording into the spill stats, for bulk operations that record the whole call at once.
+    #[inline]
+    fn extend_untracked<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        let free_stack = N - self.arr.len();
+        self.vec
+            .reserve(iter.size_hint().0.saturating_sub(free_stack));
+
+        for _ in 0..free_stack {
+            match iter.next() {
+                Some(val) => self.arr.push(val),
+                None => break,
+            }
+        }
+        self.vec.extend(iter);
+    }
+
+    /// Clone and append every element of a slice.
+    ///
+    /// The free stack slots are filled in one pass, and the rest are handed to [`Vec::extend_from_slice`] in one call.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVec};
+    ///
+    /// let mut my_combo_vec = combo_vec![1, 2; None, None];
+    /// my_combo_vec.extend_from_slice(&[3, 4, 5, 6]);
+    /// assert_eq!(my_combo_vec.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(my_combo_vec.heap_len(), 2);
+    /// ```
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        #[cfg(feature = "stats")]
+        let old_len = self.len();
+
+        let (stack, heap) = other.split_at(other.len().min(N - self.arr.len()));
+        self.arr.extend_from_slice(stack);
+        self.vec.extend_from_slice(heap);
+
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_growth(old_len, self.len(), N);
+    }
+
+    /// Replace the contents of this [`ComboVec`] with clones of the elements of a slice.
+    ///
+    /// Elements that are already in the [`ComboVec`] are overwritten with [`Clone::clone_from`],
+    /// so they can reuse any resources they own, and the heap keeps its capacity.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVec};
+    ///
+    /// let mut my_combo_vec = combo_vec![1, 2; None];
+    /// my_combo_vec.clone_from_slice(&[7, 8, 9, 10]);
+    /// assert_eq!(my_combo_vec.to_vec(), vec![7, 8, 9, 10]);
+    ///
+    /// my_combo_vec.clone_from_slice(&[1]);
+    /// assert_eq!(my_combo_vec.to_vec(), vec![1]);
+    /// ```
+    #[inline]
+    pub fn clone_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        self.truncate(src.len());
+        let (init, tail) = src.split_at(self.len());
+        self.iter_mut()
+            .zip(init)
+            .for_each(|(dst, src)| dst.clone_from(src));
+        self.extend_from_slice(tail);
+    }
+
+    /// Splits the [`ComboVec`] into two at the given index.
+    ///
+    /// Returns a new [`ComboVec`] containing the elements in the range `[at, len)`,
+    /// leaving the elements `[0, at)` in this one.
+    /// Both halves fill their stack storage before using the heap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5, 6]);
+    ///
+    /// let other = x.split_off(2);
+    /// assert_eq!(x.to_vec(), vec![1, 2]);
+    /// assert_eq!(other.to_vec(), vec![3, 4, 5, 6]);
+    /// assert_eq!(other.stack_len(), 3);
+    /// assert_eq!(other.heap_len(), 1);
+    /// ```
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        if at >= N {
+            self.vec.split_off(at - N).into_iter().collect()
+        } else {
+            let vec = mem::take(&mut self.vec);
+            self.arr.split_off(at).into_iter().chain(vec).collect()
+        }
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// `other` may have a different stack capacity than `self`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVec};
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// let mut other: ComboVec<i32, 2> = combo_vec![4, 5];
+    /// other.push(6);
+    ///
+    /// x.append(&mut other);
+    /// assert_eq!(x.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append<const M: usize>(&mut self, other: &mut ComboVec<T, M>) {
+        #[cfg(feature = "stats")]
+        let old_len = self.len();
+
+        self.extend_untracked(mem::take(&mut other.arr));
+
+        if self.len() >= N {
+            self.vec.append(&mut other.vec);
+        } else {
+            self.extend_untracked(other.vec.drain(..));
+        }
+
+        #[cfg(feature = "stats")]
+        crate::SpillStats::global().record_growth(old_len, self.len(), N);
+    }
+
+    /// Move the elements into a [`ComboVec`] with a different stack capacity, reusing the heap allocation.
+    ///
+    /// When growing, elements are moved from the heap onto the stack.
+    /// When shrinking, elements that no longer fit on the stack are moved onto the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVec};
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5]);
+    ///
+    /// let bigger: ComboVec<i32, 8> = x.into_capacity();
+    /// assert_eq!(bigger.to_vec(), vec![1, 2, 3, 4, 5]);
+    /// assert!(!bigger.spilled());
+    ///
+    /// let smaller: ComboVec<i32, 2> = bigger.into_capacity();
+    /// assert_eq!(smaller.to_vec(), vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(smaller.heap_len(), 3);
+    /// ```
+    #[doc(alias = "resize_capacity")]
+    #[must_use]
+    pub fn into_capacity<const M: usize>(mut self) -> ComboVec<T, M> {
+        let mut vec = self.vec;
+        if self.arr.len() > M {
+            let rest = self.arr.split_off(M);
+            vec.splice(0..0, rest);
+        }
+
+        let mut arr = ReArr::new();
+        arr.append(&mut self.arr);
+
+        let free_slots = M - arr.len();
+        arr.extend(vec.drain(..free_slots.min(vec.len())));
+
+        ComboVec { arr, vec }
     }
 
     /// Get this [`ComboVec`] transformed into a [`Vec`].
@@ -715,6 +1135,195 @@ impl<T, const N: usize> ComboVec<T, N> {
     pub fn ref_vec(&self) -> Vec<&T> {
         self.iter().collect()
     }
+
+    /// Get a [`ComboSlice`] view into a range of the elements,
+    /// regardless of whether they are stored on the stack or the heap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5]);
+    /// assert_eq!(x.slice(2..4).to_vec(), vec![3, 4]);
+    /// assert_eq!(x.slice(..).len(), 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> ComboSlice<'_, T, N> {
+        ComboSlice::new(&self.arr.arr[..self.arr.len()], &self.vec, range)
+    }
+
+    /// Get an iterator over `chunk_size` elements at a time, starting at the beginning.
+    ///
+    /// The last chunk will be shorter if `chunk_size` does not divide the length.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5]);
+    ///
+    /// let mut iter = x.chunks(2);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1, 2]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![3, 4]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![5]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        self.slice(..).chunks(chunk_size)
+    }
+
+    /// Get an iterator over exactly `chunk_size` elements at a time, starting at the beginning.
+    ///
+    /// If `chunk_size` does not divide the length, the last up to `chunk_size - 1`
+    /// elements are skipped.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5]);
+    ///
+    /// let mut iter = x.chunks_exact(2);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1, 2]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![3, 4]);
+    /// assert!(iter.next().is_none());
+    ///
+    /// // The skipped elements can still be viewed
+    /// assert_eq!(x.slice(x.len() - x.len() % 2..).to_vec(), vec![5]);
+    /// ```
+    #[inline]
+    pub fn chunks_exact(
+        &self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        self.slice(..).chunks_exact(chunk_size)
+    }
+
+    /// Get an iterator over `chunk_size` elements at a time, starting at the end.
+    ///
+    /// The last chunk will be shorter if `chunk_size` does not divide the length.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.extend([4, 5]);
+    ///
+    /// let mut iter = x.rchunks(2);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![4, 5]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![2, 3]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn rchunks(&self, chunk_size: usize) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        self.slice(..).rchunks(chunk_size)
+    }
+
+    /// Get an iterator over all contiguous windows of length `size`.
+    ///
+    /// The windows overlap. If the length is less than `size`, the iterator returns no values.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 2, 3];
+    /// x.push(4);
+    ///
+    /// let mut iter = x.windows(3);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1, 2, 3]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![2, 3, 4]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = ComboSlice<'_, T, N>> + '_ {
+        self.slice(..).windows(size)
+    }
+
+    /// Get an iterator over the groups of elements separated by elements that match `pred`.
+    ///
+    /// The matched element is not contained in the groups.
+    /// Like [`slice::split`], empty groups are returned for adjacent or leading/trailing matches.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![10, 40, 33];
+    /// x.push(20);
+    ///
+    /// let mut iter = x.split(|num| num % 3 == 0);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![10, 40]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![20]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn split<'a, F: FnMut(&T) -> bool + 'a>(
+        &'a self,
+        pred: F,
+    ) -> impl Iterator<Item = ComboSlice<'a, T, N>> + 'a {
+        self.slice(..).split(pred)
+    }
+
+    /// Get an iterator over runs of elements,
+    /// where `pred` returns `true` for every pair of consecutive elements in a run.
+    ///
+    /// This is the equivalent of [`slice::chunk_by`] (formerly known as `group_by`).
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 1, 2];
+    /// x.extend([2, 2, 3]);
+    ///
+    /// let mut iter = x.chunk_by(|a, b| a == b);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![1, 1]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![2, 2, 2]);
+    /// assert_eq!(iter.next().unwrap().to_vec(), vec![3]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn chunk_by<'a, F: FnMut(&T, &T) -> bool + 'a>(
+        &'a self,
+        pred: F,
+    ) -> impl Iterator<Item = ComboSlice<'a, T, N>> + 'a {
+        self.slice(..).chunk_by(pred)
+    }
 }
 
 impl<T: Clone, const N: usize> ComboVec<T, N> {
@@ -799,6 +1408,55 @@ impl<T: Clone, const N: usize> ComboVec<T, N> {
             self.vec.clear();
         }
     }
+
+    /// Create a new [`ComboVec`] by cloning and joining together the elements of every part, in order.
+    ///
+    /// The parts may have a different stack capacity than the result.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVec};
+    ///
+    /// let parts = [combo_vec![1, 2], combo_vec![3, 4]];
+    /// let joined = ComboVec::<i32, 3>::concat(&parts);
+    ///
+    /// assert_eq!(joined.to_vec(), vec![1, 2, 3, 4]);
+    /// assert_eq!(joined.stack_len(), 3);
+    /// assert_eq!(joined.heap_len(), 1);
+    /// ```
+    #[must_use]
+    pub fn concat<const M: usize>(parts: &[ComboVec<T, M>]) -> Self {
+        let len: usize = parts.iter().map(ComboVec::len).sum();
+
+        let mut joined = Self::new();
+        joined.reserve(len.saturating_sub(N));
+        joined.extend(parts.iter().flat_map(ComboVec::iter).cloned());
+        joined
+    }
+}
+
+impl<T: PartialEq, const N: usize> ComboVec<T, N> {
+    /// Removes consecutive repeated elements.
+    ///
+    /// If the [`ComboVec`] is sorted, this removes all duplicates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::combo_vec;
+    ///
+    /// let mut x = combo_vec![1, 1, 2];
+    /// x.extend([2, 3, 3, 4]);
+    /// x.dedup();
+    /// assert_eq!(x.to_vec(), vec![1, 2, 3, 4]);
+    /// assert_eq!(x.stack_len(), 3);
+    /// assert_eq!(x.heap_len(), 1);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
 }
 
 impl<T: ToString, const N: usize> ComboVec<T, N> {
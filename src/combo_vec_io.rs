@@ -0,0 +1,290 @@
+use crate::ComboVec;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use std::io::{BufRead, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+
+impl<const N: usize> Write for ComboVec<u8, N> {
+    /// Append the bytes to the end of the [`ComboVec`], spilling onto the heap if needed.
+    ///
+    /// This never fails and always writes every byte.
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.extend(buf.iter().copied());
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.extend(buf.iter().copied());
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A cursor that reads the bytes of a [`ComboVec<u8, N>`], across both the stack and the heap.
+///
+/// It works like [`std::io::Cursor`], implementing [`Read`], [`BufRead`] and [`Seek`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{ComboVec, ComboVecCursor};
+/// use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+///
+/// let mut frame = ComboVec::<u8, 4>::new();
+/// write!(frame, "hello\nworld").unwrap();
+/// assert!(frame.spilled());
+///
+/// let mut cursor = ComboVecCursor::new(frame);
+/// let mut line = String::new();
+/// cursor.read_line(&mut line).unwrap();
+/// assert_eq!(line, "hello\n");
+///
+/// cursor.seek(SeekFrom::End(-3)).unwrap();
+/// let mut rest = String::new();
+/// cursor.read_to_string(&mut rest).unwrap();
+/// assert_eq!(rest, "rld");
+/// ```
+pub struct ComboVecCursor<const N: usize> {
+    inner: ComboVec<u8, N>,
+    pos: u64,
+    // The stack bytes are stored as `Option<u8>`,
+    // so they're copied here once to hand out a contiguous slice from `fill_buf`.
+    stack: [u8; N],
+    // Set by `get_mut`, since the bytes may have changed since they were copied.
+    stack_stale: bool,
+}
+
+impl<const N: usize> Clone for ComboVecCursor<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            pos: self.pos,
+            stack: self.stack,
+            stack_stale: self.stack_stale,
+        }
+    }
+}
+
+impl<const N: usize> Debug for ComboVecCursor<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ComboVecCursor")
+            .field("inner", &self.inner)
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const N: usize> ComboVecCursor<N> {
+    const fn copy_stack(inner: &ComboVec<u8, N>) -> [u8; N] {
+        let mut stack = [0; N];
+        let mut i = 0;
+        while i < N {
+            if let Some(byte) = inner.arr.arr[i] {
+                stack[i] = byte;
+            }
+            i += 1;
+        }
+
+        stack
+    }
+
+    /// Create a new cursor at the start of the given bytes.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVecCursor};
+    ///
+    /// let cursor = ComboVecCursor::new(combo_vec![1u8, 2, 3]);
+    /// assert_eq!(cursor.position(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(inner: ComboVec<u8, N>) -> Self {
+        Self {
+            stack: Self::copy_stack(&inner),
+            inner,
+            pos: 0,
+            stack_stale: false,
+        }
+    }
+
+    /// Consume the cursor, returning the underlying bytes.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVecCursor};
+    ///
+    /// let cursor = ComboVecCursor::new(combo_vec![1u8, 2, 3]);
+    /// assert_eq!(cursor.into_inner(), combo_vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> ComboVec<u8, N> {
+        self.inner
+    }
+
+    /// Get a reference to the underlying bytes.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVecCursor};
+    ///
+    /// let cursor = ComboVecCursor::new(combo_vec![1u8, 2, 3]);
+    /// assert_eq!(cursor.get_ref().len(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn get_ref(&self) -> &ComboVec<u8, N> {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the underlying bytes.
+    ///
+    /// Changing the bytes doesn't move the cursor.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVecCursor};
+    /// use std::io::Read;
+    ///
+    /// let mut cursor = ComboVecCursor::new(combo_vec![1u8, 2, 3]);
+    /// cursor.get_mut().push(4);
+    ///
+    /// let mut bytes = Vec::new();
+    /// cursor.read_to_end(&mut bytes).unwrap();
+    /// assert_eq!(bytes, [1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn get_mut(&mut self) -> &mut ComboVec<u8, N> {
+        self.stack_stale = true;
+        &mut self.inner
+    }
+
+    /// Get the current position of the cursor.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVecCursor};
+    /// use std::io::Read;
+    ///
+    /// let mut cursor = ComboVecCursor::new(combo_vec![1u8, 2, 3]);
+    /// cursor.read_exact(&mut [0; 2]).unwrap();
+    /// assert_eq!(cursor.position(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Move the cursor to the given position.
+    ///
+    /// The position may be past the end of the bytes, in which case reads return nothing.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, ComboVecCursor};
+    /// use std::io::Read;
+    ///
+    /// let mut cursor = ComboVecCursor::new(combo_vec![1u8, 2, 3]);
+    /// cursor.set_position(2);
+    ///
+    /// let mut byte = [0];
+    /// cursor.read_exact(&mut byte).unwrap();
+    /// assert_eq!(byte, [3]);
+    /// ```
+    #[inline]
+    pub const fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+}
+
+impl<const N: usize> Read for ComboVecCursor<N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let mut read = 0;
+
+        // A read may straddle the stack and the heap, so keep going until one of them is exhausted.
+        while read < buf.len() {
+            let src = self.fill_buf()?;
+            if src.is_empty() {
+                break;
+            }
+
+            let amt = src.len().min(buf.len() - read);
+            buf[read..read + amt].copy_from_slice(&src[..amt]);
+            self.consume(amt);
+            read += amt;
+        }
+
+        Ok(read)
+    }
+}
+
+impl<const N: usize> BufRead for ComboVecCursor<N> {
+    /// Get the unread bytes of either the stack or the heap, whichever the cursor is currently in.
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        let pos = usize::try_from(self.pos).unwrap_or(usize::MAX);
+        let stack_len = self.inner.arr.len();
+
+        if pos < stack_len {
+            if self.stack_stale {
+                self.stack = Self::copy_stack(&self.inner);
+                self.stack_stale = false;
+            }
+
+            return Ok(&self.stack[pos..stack_len]);
+        }
+
+        let heap = &self.inner.vec;
+        Ok(heap.get(pos - stack_len..).unwrap_or_default())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+
+impl<const N: usize> Seek for ComboVecCursor<N> {
+    #[inline]
+    fn seek(&mut self, style: SeekFrom) -> Result<u64> {
+        let (base, offset) = match style {
+            SeekFrom::Start(pos) => {
+                self.pos = pos;
+                return Ok(pos);
+            }
+            SeekFrom::End(offset) => (self.inner.len() as u64, offset),
+            SeekFrom::Current(offset) => (self.pos, offset),
+        };
+
+        let pos = base.checked_add_signed(offset).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        self.pos = pos;
+        Ok(pos)
+    }
+
+    #[inline]
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.pos)
+    }
+}
@@ -0,0 +1,208 @@
+use crate::ComboVec;
+use alloc::vec::Vec;
+use core::{
+    cell::RefCell,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem,
+    ops::{Deref, DerefMut},
+};
+
+/// A pool of heap buffers that [`ComboVec`]s spill into, so spilled storage is reused instead of reallocated.
+///
+/// [`ComboVecPool::get`] hands out an empty [`ComboVec`] wrapped in a [`PooledComboVec`] guard.
+/// When the guard is dropped, the elements are dropped and the heap buffer goes back to the pool with its capacity intact.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::ComboVecPool;
+///
+/// let pool = ComboVecPool::<i32, 4>::new();
+///
+/// for frame in 0..3 {
+///     let mut items = pool.get();
+///     items.extend(0..16);
+///     assert!(items.spilled());
+///
+///     if frame > 0 {
+///         // The heap buffer from the last frame was reused
+///         assert_eq!(pool.available(), 0);
+///     }
+/// }
+///
+/// assert_eq!(pool.available(), 1);
+/// ```
+pub struct ComboVecPool<T, const N: usize> {
+    buffers: RefCell<Vec<Vec<T>>>,
+}
+
+impl<T, const N: usize> Default for ComboVecPool<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ComboVecPool<T, N> {
+    /// Create a new, empty [`ComboVecPool`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboVecPool;
+    ///
+    /// let pool = ComboVecPool::<i32, 4>::new();
+    /// assert_eq!(pool.available(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            buffers: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Get an empty [`ComboVec`] that spills into a heap buffer from the pool, if there is one.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboVecPool;
+    ///
+    /// let pool = ComboVecPool::<i32, 2>::new();
+    /// {
+    ///     let mut items = pool.get();
+    ///     items.extend([1, 2, 3, 4]);
+    /// }
+    ///
+    /// let items = pool.get();
+    /// assert!(items.is_empty());
+    /// assert!(items.heap_capacity() >= 2);
+    /// ```
+    #[inline]
+    pub fn get(&self) -> PooledComboVec<'_, T, N> {
+        let buffer = self.buffers.borrow_mut().pop().unwrap_or_default();
+
+        PooledComboVec {
+            pool: self,
+            vec: ComboVec::from_heap_buffer(buffer),
+        }
+    }
+
+    /// Give a heap buffer to the pool, if it has been allocated.
+    #[inline]
+    fn recycle(&self, buffer: Vec<T>) {
+        if buffer.capacity() != 0 {
+            self.buffers.borrow_mut().push(buffer);
+        }
+    }
+
+    /// How many heap buffers are waiting to be reused.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboVecPool;
+    ///
+    /// let pool = ComboVecPool::<i32, 2>::new();
+    ///
+    /// // Stack-only vectors never give the pool a buffer
+    /// pool.get().push(1);
+    /// assert_eq!(pool.available(), 0);
+    ///
+    /// pool.get().extend([1, 2, 3]);
+    /// assert_eq!(pool.available(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.buffers.borrow().len()
+    }
+
+    /// Free every heap buffer in the pool.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ComboVecPool;
+    ///
+    /// let pool = ComboVecPool::<i32, 2>::new();
+    /// pool.get().extend([1, 2, 3]);
+    /// pool.clear();
+    /// assert_eq!(pool.available(), 0);
+    /// ```
+    #[inline]
+    pub fn clear(&self) {
+        self.buffers.borrow_mut().clear();
+    }
+}
+
+impl<T, const N: usize> Debug for ComboVecPool<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ComboVecPool")
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+/// A [`ComboVec`] borrowed from a [`ComboVecPool`], which returns its heap buffer to the pool when dropped.
+///
+/// Returned by [`ComboVecPool::get`].
+pub struct PooledComboVec<'a, T, const N: usize> {
+    pool: &'a ComboVecPool<T, N>,
+    vec: ComboVec<T, N>,
+}
+
+impl<T, const N: usize> PooledComboVec<'_, T, N> {
+    /// Take the [`ComboVec`] out of the guard, so its heap buffer is not returned to the pool.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{ComboVecPool, PooledComboVec};
+    ///
+    /// let pool = ComboVecPool::<i32, 2>::new();
+    /// let mut items = pool.get();
+    /// items.extend([1, 2, 3]);
+    ///
+    /// let items = PooledComboVec::into_inner(items);
+    /// assert_eq!(items.to_vec(), vec![1, 2, 3]);
+    /// assert_eq!(pool.available(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_inner(mut this: Self) -> ComboVec<T, N> {
+        mem::replace(&mut this.vec, ComboVec::new())
+    }
+}
+
+impl<T, const N: usize> Deref for PooledComboVec<'_, T, N> {
+    type Target = ComboVec<T, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T, const N: usize> DerefMut for PooledComboVec<'_, T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+impl<T, const N: usize> Drop for PooledComboVec<'_, T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        self.pool.recycle(self.vec.take_heap_buffer());
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for PooledComboVec<'_, T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.vec.fmt(f)
+    }
+}
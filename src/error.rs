@@ -0,0 +1,90 @@
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+/// The error returned when an operation would require more room than a fixed-size array has.
+///
+/// Operations that consume their input return it inside of the error,
+/// and it can be recovered with [`CapacityError::into_inner`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_arr, ReArr};
+///
+/// let mut my_re_arr = re_arr![1, 2, 3; None];
+/// let mut other = re_arr![4, 5];
+///
+/// let err = my_re_arr.try_append(&mut other).unwrap_err();
+/// assert_eq!(err.required(), 5);
+/// assert_eq!(err.capacity(), 4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapacityError<T = ()> {
+    required: usize,
+    capacity: usize,
+    inner: T,
+}
+
+impl CapacityError {
+    #[inline]
+    pub(crate) const fn new(required: usize, capacity: usize) -> Self {
+        Self {
+            required,
+            capacity,
+            inner: (),
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn with_inner<T>(self, inner: T) -> CapacityError<T> {
+        CapacityError {
+            required: self.required,
+            capacity: self.capacity,
+            inner,
+        }
+    }
+}
+
+impl<T> CapacityError<T> {
+    /// How many elements the operation needed room for.
+    #[must_use]
+    #[inline]
+    pub const fn required(&self) -> usize {
+        self.required
+    }
+
+    /// How many elements could be stored.
+    #[must_use]
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Recover the value that the failed operation consumed.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let my_re_arr = re_arr![1, 2, 3];
+    /// let err = my_re_arr.try_into_capacity::<2>().unwrap_err();
+    /// assert_eq!(err.into_inner(), re_arr![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> Display for CapacityError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "required capacity of {} exceeds the array capacity of {}",
+            self.required, self.capacity
+        )
+    }
+}
+
+impl<T: Debug> core::error::Error for CapacityError<T> {}
@@ -4,15 +4,129 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "alloc")]
 #[macro_use]
 mod combo_vec;
 
+#[cfg(feature = "alloc")]
+mod boxed_combo_vec;
+#[cfg(feature = "alloc")]
+mod combo_binary_heap;
+#[cfg(feature = "alloc")]
+mod combo_bitset;
+#[cfg(feature = "alloc")]
+mod combo_grid;
+#[cfg(feature = "alloc")]
+#[macro_use]
+mod combo_map;
+#[cfg(feature = "alloc")]
+#[macro_use]
+mod combo_set;
+#[cfg(feature = "alloc")]
+mod combo_slab;
+#[cfg(feature = "alloc")]
+mod combo_slice;
+#[cfg(feature = "std")]
+mod combo_vec_io;
+#[cfg(feature = "alloc")]
+mod combo_vec_pool;
+#[cfg(feature = "alloc")]
+mod tail_combo_vec;
+
+#[cfg(feature = "alloc")]
+pub use boxed_combo_vec::BoxedComboVec;
+#[cfg(feature = "alloc")]
+pub use combo_binary_heap::{ComboBinaryHeap, ComboBinaryHeapPeekMut};
+#[cfg(feature = "alloc")]
+pub use combo_bitset::ComboBitSet;
+#[cfg(feature = "alloc")]
+pub use combo_grid::ComboGrid;
+#[cfg(feature = "alloc")]
+pub use combo_map::{ComboMap, ComboMapEntry, ComboMapOccupiedEntry, ComboMapVacantEntry};
+#[cfg(feature = "alloc")]
+pub use combo_set::ComboSet;
+#[cfg(feature = "alloc")]
+pub use combo_slab::ComboSlab;
+#[cfg(feature = "alloc")]
+pub use combo_slice::ComboSlice;
 #[cfg(feature = "alloc")]
 pub use combo_vec::ComboVec;
+#[cfg(feature = "std")]
+pub use combo_vec_io::ComboVecCursor;
+#[cfg(feature = "alloc")]
+pub use combo_vec_pool::{ComboVecPool, PooledComboVec};
+#[cfg(feature = "alloc")]
+pub use tail_combo_vec::TailComboVec;
+
+#[cfg(feature = "stats")]
+mod capacity_advisor;
+#[cfg(feature = "stats")]
+mod stats;
+
+#[cfg(feature = "stats")]
+pub use capacity_advisor::{combo_vec_size_of, CapacityAdvisor, CapacityRecommendation};
+#[cfg(feature = "stats")]
+pub use stats::{SpillStats, SpillStatsSnapshot, HISTOGRAM_BUCKETS};
+
+#[cfg(feature = "rayon")]
+mod par_iter;
+#[cfg(feature = "proptest")]
+mod proptest_impls;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+
+#[cfg(feature = "rayon")]
+pub use par_iter::{
+    ComboVecParIter, ComboVecParIterMut, ComboVecParIterRef, ReArrParIter, ReArrParIterMut,
+    ReArrParIterRef,
+};
+#[cfg(feature = "proptest")]
+pub use proptest_impls::{InlineSeqStrategy, InlineSeqValueTree};
 
 #[macro_use]
 mod re_arr;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "bincode")]
+mod bincode_codec;
+#[cfg(feature = "borsh")]
+mod borsh_codec;
+#[cfg(feature = "bytes")]
+mod bytes_buf;
+mod error;
+#[cfg(feature = "bytemuck")]
+mod pod_arr;
+#[cfg(feature = "embedded-io")]
+mod re_arr_io;
+mod re_binary_heap;
+mod re_grid;
+#[macro_use]
+mod re_map;
+#[macro_use]
+mod re_ring;
+#[macro_use]
+mod re_set;
+mod re_slab;
+mod slot;
+
+#[cfg(all(feature = "bytes", feature = "alloc"))]
+pub use bytes_buf::ComboVecBuf;
+#[cfg(feature = "bytes")]
+pub use bytes_buf::ReArrBuf;
+pub use error::CapacityError;
+#[cfg(feature = "bytemuck")]
+pub use pod_arr::{FromBytesError, PodArr};
 pub use re_arr::ReArr;
+#[cfg(feature = "embedded-io")]
+pub use re_arr_io::ReArrReader;
+pub use re_binary_heap::{ReBinaryHeap, ReBinaryHeapPeekMut};
+pub use re_grid::ReGrid;
+pub use re_map::{ReMap, ReMapEntry, ReMapOccupiedEntry, ReMapVacantEntry};
+pub use re_ring::ReRing;
+pub use re_set::ReSet;
+pub use re_slab::ReSlab;
+pub use slot::Slot;
@@ -0,0 +1,134 @@
+use crate::{ComboVec, ReArr};
+use alloc::vec::Vec;
+use rayon::{
+    array::IntoIter as ParArrayIter,
+    iter::{
+        Chain, FromParallelIterator, IndexedParallelIterator, IntoParallelIterator,
+        IntoParallelRefIterator, IntoParallelRefMutIterator, Map, ParallelExtend, ParallelIterator,
+        Take,
+    },
+    slice::{Iter as ParSliceIter, IterMut as ParSliceIterMut},
+    vec::IntoIter as ParVecIter,
+};
+
+// The first `len` slots of a `ReArr` are always `Some`,
+// so taking that many slots keeps the iterators indexed.
+type ParSlot<T> = fn(Option<T>) -> T;
+type ParSlotRef<'a, T> = fn(&'a Option<T>) -> &'a T;
+type ParSlotMut<'a, T> = fn(&'a mut Option<T>) -> &'a mut T;
+
+/// A parallel iterator over the elements of a [`ReArr`].
+pub type ReArrParIter<T, const N: usize> = Map<Take<ParArrayIter<Option<T>, N>>, ParSlot<T>>;
+/// A parallel iterator over references to the elements of a [`ReArr`].
+pub type ReArrParIterRef<'a, T> = Map<Take<ParSliceIter<'a, Option<T>>>, ParSlotRef<'a, T>>;
+/// A parallel iterator over mutable references to the elements of a [`ReArr`].
+pub type ReArrParIterMut<'a, T> = Map<Take<ParSliceIterMut<'a, Option<T>>>, ParSlotMut<'a, T>>;
+
+impl<T: Send, const N: usize> IntoParallelIterator for ReArr<T, N> {
+    type Item = T;
+    type Iter = ReArrParIter<T, N>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        let len = self.len();
+        self.arr.into_par_iter().take(len).map(Option::unwrap)
+    }
+}
+
+impl<'a, T: Sync, const N: usize> IntoParallelIterator for &'a ReArr<T, N> {
+    type Item = &'a T;
+    type Iter = ReArrParIterRef<'a, T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        let slot: ParSlotRef<'a, T> = |slot| slot.as_ref().unwrap();
+        self.arr.par_iter().take(self.len()).map(slot)
+    }
+}
+
+impl<'a, T: Send, const N: usize> IntoParallelIterator for &'a mut ReArr<T, N> {
+    type Item = &'a mut T;
+    type Iter = ReArrParIterMut<'a, T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        let len = self.len();
+        let slot: ParSlotMut<'a, T> = |slot| slot.as_mut().unwrap();
+        self.arr.par_iter_mut().take(len).map(slot)
+    }
+}
+
+impl<T: Send, const N: usize> FromParallelIterator<T> for ReArr<T, N> {
+    /// Collect the elements of a parallel iterator into a [`ReArr`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there are more than `N` elements.
+    #[inline]
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        Vec::from_par_iter(par_iter).into_iter().collect()
+    }
+}
+
+impl<T: Send, const N: usize> ParallelExtend<T> for ReArr<T, N> {
+    /// Extend the [`ReArr`] with the elements of a parallel iterator, keeping their order.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the array becomes full.
+    #[inline]
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        self.extend(Vec::from_par_iter(par_iter));
+    }
+}
+
+/// A parallel iterator over the elements of a [`ComboVec`], split between the stack and the heap.
+pub type ComboVecParIter<T, const N: usize> = Chain<ReArrParIter<T, N>, ParVecIter<T>>;
+/// A parallel iterator over references to the elements of a [`ComboVec`], split between the stack and the heap.
+pub type ComboVecParIterRef<'a, T> = Chain<ReArrParIterRef<'a, T>, ParSliceIter<'a, T>>;
+/// A parallel iterator over mutable references to the elements of a [`ComboVec`], split between the stack and the heap.
+pub type ComboVecParIterMut<'a, T> = Chain<ReArrParIterMut<'a, T>, ParSliceIterMut<'a, T>>;
+
+impl<T: Send, const N: usize> IntoParallelIterator for ComboVec<T, N> {
+    type Item = T;
+    type Iter = ComboVecParIter<T, N>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.arr.into_par_iter().chain(self.vec.into_par_iter())
+    }
+}
+
+impl<'a, T: Sync, const N: usize> IntoParallelIterator for &'a ComboVec<T, N> {
+    type Item = &'a T;
+    type Iter = ComboVecParIterRef<'a, T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.arr.par_iter().chain(self.vec.par_iter())
+    }
+}
+
+impl<'a, T: Send, const N: usize> IntoParallelIterator for &'a mut ComboVec<T, N> {
+    type Item = &'a mut T;
+    type Iter = ComboVecParIterMut<'a, T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.arr.par_iter_mut().chain(self.vec.par_iter_mut())
+    }
+}
+
+impl<T: Send, const N: usize> FromParallelIterator<T> for ComboVec<T, N> {
+    #[inline]
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        Vec::from_par_iter(par_iter).into_iter().collect()
+    }
+}
+
+impl<T: Send, const N: usize> ParallelExtend<T> for ComboVec<T, N> {
+    #[inline]
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        self.extend(Vec::from_par_iter(par_iter));
+    }
+}
@@ -0,0 +1,448 @@
+use crate::{CapacityError, ReArr};
+use bytemuck::{cast_slice, cast_slice_mut, Pod};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    mem::size_of,
+    ops,
+};
+
+/// A fixed-size array of [`Pod`] elements that can be viewed as bytes without copying.
+///
+/// Unlike [`ReArr`], elements aren't wrapped in an `Option`.
+/// Unused slots hold zeroed values, so the live elements are one contiguous `[T]` with a well-defined layout.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::PodArr;
+///
+/// let mut arr = PodArr::<u32, 64>::new();
+/// arr.push(1);
+/// arr.push(0x0102_0304);
+///
+/// let bytes = arr.as_bytes();
+/// assert_eq!(bytes.len(), 8);
+///
+/// let copy = PodArr::<u32, 64>::try_from_bytes(bytes).unwrap();
+/// assert_eq!(copy, arr);
+/// ```
+#[derive(Clone, Copy)]
+pub struct PodArr<T: Pod, const N: usize> {
+    arr: [T; N],
+    len: usize,
+}
+
+impl<T: Pod + PartialEq, const N: usize> PartialEq for PodArr<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Pod + Eq, const N: usize> Eq for PodArr<T, N> {}
+
+impl<T: Pod + Hash, const N: usize> Hash for PodArr<T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: Pod, const N: usize> Default for PodArr<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Pod, const N: usize> PodArr<T, N> {
+    /// Create a new, empty [`PodArr`] with room for `N` elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let arr = PodArr::<u8, 16>::new();
+    /// assert!(arr.is_empty());
+    /// assert_eq!(arr.capacity(), 16);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            arr: [T::zeroed(); N],
+            len: 0,
+        }
+    }
+
+    /// Create a [`PodArr`] by copying elements out of their byte representation.
+    ///
+    /// The bytes don't need to be aligned for `T`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`FromBytesError::Slop`] if the number of bytes isn't a multiple of the size of `T`,
+    /// or [`FromBytesError::Capacity`] if there are more than `N` elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{FromBytesError, PodArr};
+    ///
+    /// let arr = PodArr::<u16, 2>::try_from_bytes(&[1, 0, 2, 0]).unwrap();
+    /// assert_eq!(arr.as_slice(), &[1u16.to_le(), 2u16.to_le()]);
+    ///
+    /// assert!(matches!(PodArr::<u16, 2>::try_from_bytes(&[1, 0, 2]), Err(FromBytesError::Slop)));
+    /// assert!(matches!(PodArr::<u16, 1>::try_from_bytes(&[1, 0, 2, 0]), Err(FromBytesError::Capacity(_))));
+    /// ```
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        let size = size_of::<T>();
+        if size == 0 || bytes.len() % size != 0 {
+            return Err(FromBytesError::Slop);
+        }
+
+        let len = bytes.len() / size;
+        if len > N {
+            return Err(FromBytesError::Capacity(CapacityError::new(len, N)));
+        }
+
+        let mut arr = Self::new();
+        cast_slice_mut::<T, u8>(&mut arr.arr[..len]).copy_from_slice(bytes);
+        arr.len = len;
+
+        Ok(arr)
+    }
+
+    /// Get the live elements as bytes.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u16, 4>::new();
+    /// arr.push(u16::from_le_bytes([1, 2]));
+    /// assert_eq!(arr.as_bytes(), &[1, 2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        cast_slice(self.as_slice())
+    }
+
+    /// Get the live elements as mutable bytes.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u16, 4>::new();
+    /// arr.push(0);
+    /// arr.as_bytes_mut().copy_from_slice(&[1, 2]);
+    /// assert_eq!(arr[0], u16::from_le_bytes([1, 2]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        cast_slice_mut(self.as_mut_slice())
+    }
+
+    /// Get the live elements as a slice.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.push(1);
+    /// arr.push(2);
+    /// assert_eq!(arr.as_slice(), &[1, 2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.arr[..self.len]
+    }
+
+    /// Get the live elements as a mutable slice.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.push(1);
+    /// arr.as_mut_slice()[0] = 5;
+    /// assert_eq!(arr.as_slice(), &[5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.arr[..self.len]
+    }
+
+    /// Push an element to the end of the array.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the array is full.
+    /// See [`PodArr::try_push`] for a non-panicking version.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.push(1);
+    /// assert_eq!(arr.len(), 1);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, val: T) {
+        if let Err(err) = self.try_push(val) {
+            panic!("{err}");
+        }
+    }
+
+    /// Push an element to the end of the array.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the array is full.
+    /// The element can be recovered with [`CapacityError::into_inner`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 1>::new();
+    /// assert_eq!(arr.try_push(1), Ok(()));
+    /// assert_eq!(arr.try_push(2).unwrap_err().into_inner(), 2);
+    /// ```
+    pub const fn try_push(&mut self, val: T) -> Result<(), CapacityError<T>> {
+        if self.len == N {
+            return Err(CapacityError::new(N + 1, N).with_inner(val));
+        }
+
+        self.arr[self.len] = val;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove the last element from the array and return it, or `None` if it is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.push(1);
+    /// assert_eq!(arr.pop(), Some(1));
+    /// assert_eq!(arr.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        Some(core::mem::replace(&mut self.arr[self.len], T::zeroed()))
+    }
+
+    /// Copy every element of a slice to the end of the array.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the elements don't fit, in which case nothing is copied.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.try_extend_from_slice(&[1, 2, 3]).unwrap();
+    /// assert!(arr.try_extend_from_slice(&[4, 5]).is_err());
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn try_extend_from_slice(&mut self, vals: &[T]) -> Result<(), CapacityError> {
+        let new_len = self.len + vals.len();
+        if new_len > N {
+            return Err(CapacityError::new(new_len, N));
+        }
+
+        self.arr[self.len..new_len].copy_from_slice(vals);
+        self.len = new_len;
+        Ok(())
+    }
+
+    /// Shortens the array, keeping the first `len` elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.try_extend_from_slice(&[1, 2, 3]).unwrap();
+    /// arr.truncate(1);
+    /// assert_eq!(arr.as_slice(), &[1]);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.arr[len..self.len].fill(T::zeroed());
+            self.len = len;
+        }
+    }
+
+    /// Remove all elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.push(1);
+    /// arr.clear();
+    /// assert!(arr.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// How many elements are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.push(1);
+    /// assert_eq!(arr.len(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let arr = PodArr::<u32, 4>::new();
+    /// assert!(arr.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// How many elements can be stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let arr = PodArr::<u32, 4>::new();
+    /// assert_eq!(arr.capacity(), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Get an iterator over the elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::PodArr;
+    ///
+    /// let mut arr = PodArr::<u32, 4>::new();
+    /// arr.try_extend_from_slice(&[1, 2]).unwrap();
+    /// assert_eq!(arr.iter().sum::<u32>(), 3);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        self.as_slice().iter()
+    }
+}
+
+impl<T: Pod, const N: usize> ops::Index<usize> for PodArr<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.as_slice()[idx]
+    }
+}
+
+impl<T: Pod, const N: usize> ops::IndexMut<usize> for PodArr<T, N> {
+    #[inline]
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.as_mut_slice()[idx]
+    }
+}
+
+impl<T: Pod, const N: usize> From<ReArr<T, N>> for PodArr<T, N> {
+    #[inline]
+    fn from(re_arr: ReArr<T, N>) -> Self {
+        let mut arr = Self::new();
+        re_arr.into_iter().for_each(|val| arr.push(val));
+        arr
+    }
+}
+
+impl<T: Pod, const N: usize> From<PodArr<T, N>> for ReArr<T, N> {
+    #[inline]
+    fn from(pod_arr: PodArr<T, N>) -> Self {
+        pod_arr.as_slice().iter().copied().collect()
+    }
+}
+
+impl<T: Pod + Debug, const N: usize> Debug for PodArr<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// The error returned when a [`PodArr`] can't be created from bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromBytesError {
+    /// The number of bytes isn't a multiple of the size of an element.
+    Slop,
+    /// There are more elements than the array can store.
+    Capacity(CapacityError),
+}
+
+impl Display for FromBytesError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Slop => write!(f, "byte length is not a multiple of the element size"),
+            Self::Capacity(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl core::error::Error for FromBytesError {}
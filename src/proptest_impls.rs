@@ -0,0 +1,208 @@
+use crate::{ComboVec, ReArr};
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    strategy::{NewTree, Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+
+/// A [`Strategy`] that generates a [`ComboVec`] or [`ReArr`] with up to `max_len` elements.
+///
+/// Values shrink towards the inline capacity `N` before they shrink towards being empty.
+pub struct InlineSeqStrategy<S, C, const N: usize> {
+    element: S,
+    max_len: usize,
+    collection: PhantomData<fn() -> C>,
+}
+
+impl<S: Clone, C, const N: usize> Clone for InlineSeqStrategy<S, C, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            element: self.element.clone(),
+            max_len: self.max_len,
+            collection: PhantomData,
+        }
+    }
+}
+
+impl<S: Debug, C, const N: usize> Debug for InlineSeqStrategy<S, C, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InlineSeqStrategy")
+            .field("element", &self.element)
+            .field("max_len", &self.max_len)
+            .finish()
+    }
+}
+
+impl<S, C, const N: usize> Strategy for InlineSeqStrategy<S, C, N>
+where
+    S: Strategy,
+    C: FromIterator<S::Value> + Debug,
+{
+    type Tree = InlineSeqValueTree<S::Tree, C, N>;
+    type Value = C;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let len = (0..=self.max_len).new_tree(runner)?.current();
+        let elements = (0..len)
+            .map(|_| self.element.new_tree(runner))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(InlineSeqValueTree {
+            elements,
+            len,
+            min_len: 0,
+            prev_len: None,
+            shrink: 0,
+            prev_shrink: None,
+            collection: PhantomData,
+        })
+    }
+}
+
+/// The [`ValueTree`] of an [`InlineSeqStrategy`].
+///
+/// The length is shrunk first with a binary search that tries `N` and then `N + 1` whenever they are in range,
+/// so failures that depend on spilling settle on the smallest spilled length.
+/// After that, each element is shrunk in turn.
+pub struct InlineSeqValueTree<T, C, const N: usize> {
+    elements: Vec<T>,
+    len: usize,
+    // The shortest length that may still fail.
+    min_len: usize,
+    prev_len: Option<usize>,
+    shrink: usize,
+    prev_shrink: Option<usize>,
+    collection: PhantomData<fn() -> C>,
+}
+
+impl<T: Clone, C, const N: usize> Clone for InlineSeqValueTree<T, C, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            elements: self.elements.clone(),
+            len: self.len,
+            min_len: self.min_len,
+            prev_len: self.prev_len,
+            shrink: self.shrink,
+            prev_shrink: self.prev_shrink,
+            collection: PhantomData,
+        }
+    }
+}
+
+impl<T: Debug, C, const N: usize> Debug for InlineSeqValueTree<T, C, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InlineSeqValueTree")
+            .field("elements", &self.elements)
+            .field("len", &self.len)
+            .field("min_len", &self.min_len)
+            .field("shrink", &self.shrink)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, C, const N: usize> InlineSeqValueTree<T, C, N> {
+    const fn next_len(&self) -> usize {
+        if self.min_len <= N && N < self.len {
+            N
+        } else if self.min_len <= N + 1 && N + 1 < self.len {
+            N + 1
+        } else {
+            self.min_len + (self.len - self.min_len) / 2
+        }
+    }
+}
+
+impl<T, C, const N: usize> ValueTree for InlineSeqValueTree<T, C, N>
+where
+    T: ValueTree,
+    C: FromIterator<T::Value> + Debug,
+{
+    type Value = C;
+
+    fn current(&self) -> Self::Value {
+        self.elements[..self.len]
+            .iter()
+            .map(ValueTree::current)
+            .collect()
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.prev_len = None;
+        self.prev_shrink = None;
+
+        if self.min_len < self.len {
+            self.prev_len = Some(self.len);
+            self.len = self.next_len();
+            return true;
+        }
+
+        while self.shrink < self.len {
+            if self.elements[self.shrink].simplify() {
+                self.prev_shrink = Some(self.shrink);
+                return true;
+            }
+
+            self.shrink += 1;
+        }
+
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        if let Some(len) = self.prev_len.take() {
+            // The shorter value passed, so the failure needs more elements than that.
+            self.min_len = self.len + 1;
+            self.len = len;
+            return true;
+        }
+
+        let Some(idx) = self.prev_shrink else {
+            return false;
+        };
+
+        if self.elements[idx].complicate() {
+            true
+        } else {
+            self.prev_shrink = None;
+            false
+        }
+    }
+}
+
+impl<T: Arbitrary, const N: usize> Arbitrary for ReArr<T, N> {
+    type Parameters = T::Parameters;
+    type Strategy = InlineSeqStrategy<T::Strategy, Self, N>;
+
+    #[inline]
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        InlineSeqStrategy {
+            element: any_with::<T>(args),
+            max_len: N,
+            collection: PhantomData,
+        }
+    }
+}
+
+impl<T: Arbitrary, const N: usize> Arbitrary for ComboVec<T, N> {
+    type Parameters = T::Parameters;
+    type Strategy = InlineSeqStrategy<T::Strategy, Self, N>;
+
+    /// Generate a [`ComboVec`] that is equally likely to fit on the stack or to have spilled onto the heap.
+    #[inline]
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        InlineSeqStrategy {
+            element: any_with::<T>(args),
+            max_len: N.saturating_mul(2).saturating_add(1),
+            collection: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{ComboVec, ReArr};
+use alloc::{boxed::Box, vec::Vec};
+use core::iter;
+use quickcheck::{Arbitrary, Gen};
+
+fn arbitrary_len(g: &mut Gen, max_len: usize) -> usize {
+    usize::arbitrary(g) % max_len.saturating_add(1)
+}
+
+// Shrinking tries the lengths on either side of the inline boundary before anything else,
+// then falls back to shrinking the elements like a `Vec`.
+fn shrink_seq<T: Arbitrary, C: FromIterator<T> + 'static>(
+    vals: &[T],
+    boundary: usize,
+) -> Box<dyn Iterator<Item = C>> {
+    let truncated = [boundary.saturating_add(1), boundary]
+        .into_iter()
+        .filter(|&len| len < vals.len())
+        .map(|len| vals[..len].iter().cloned().collect())
+        .collect::<Vec<C>>();
+
+    let shrunk = vals
+        .to_vec()
+        .shrink()
+        .map(|vals| vals.into_iter().collect());
+    Box::new(truncated.into_iter().chain(shrunk))
+}
+
+impl<T: Arbitrary, const N: usize> Arbitrary for ReArr<T, N> {
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = arbitrary_len(g, N);
+        iter::repeat_with(|| T::arbitrary(g)).take(len).collect()
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        shrink_seq(&self.to_vec(), N)
+    }
+}
+
+impl<T: Arbitrary, const N: usize> Arbitrary for ComboVec<T, N> {
+    /// Generate a [`ComboVec`] that is equally likely to fit on the stack or to have spilled onto the heap.
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = arbitrary_len(g, N.saturating_mul(2).saturating_add(1));
+        iter::repeat_with(|| T::arbitrary(g)).take(len).collect()
+    }
+
+    /// Shrink towards the inline capacity first, so a failure that needs the heap shrinks to `N + 1` elements.
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        shrink_seq(&self.to_vec(), N)
+    }
+}
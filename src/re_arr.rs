@@ -4,13 +4,15 @@ use alloc::{
     vec::Vec,
 };
 
+use crate::{CapacityError, Slot};
 use core::{
     array::IntoIter as ArrayIter,
     cmp::Ordering,
-    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    fmt::{self, Debug, Display, Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
-    iter::Flatten,
-    ops,
+    iter::{Flatten, Take},
+    marker::PhantomData,
+    mem, ops,
 };
 
 /// Easy way to create a new [`ReArr`] with elements.
@@ -50,6 +52,48 @@ macro_rules! re_arr {
     );
 }
 
+/// Fail the build if a type is larger than the given number of bytes.
+///
+/// This is meant to catch a [`ReArr`] or `ComboVec` whose inline storage has grown too big to move around cheaply
+/// or to keep on a thread with a small stack, before it becomes a stack overflow at runtime.
+/// With the `alloc` feature, a `BoxedComboVec` stays the size of a few pointers no matter how big its inline storage is.
+///
+/// The check expands to a `const` item, so it can be used at the module level or inside a function,
+/// but it can't refer to generic parameters.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{assert_stack_size, ReArr};
+///
+/// const STACK_BUDGET: usize = 1024;
+///
+/// assert_stack_size!(ReArr<i32, 64>, STACK_BUDGET);
+/// #[cfg(feature = "alloc")]
+/// assert_stack_size!(combo_vec::BoxedComboVec<i32, 2048>, STACK_BUDGET);
+/// ```
+///
+/// ```rust,compile_fail
+/// use combo_vec::{assert_stack_size, ReArr};
+///
+/// assert_stack_size!(ReArr<i32, 2048>, 1024);
+/// ```
+#[macro_export]
+macro_rules! assert_stack_size {
+    ($ty:ty, $max_bytes:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$ty>() <= $max_bytes,
+            ::core::concat!(
+                "`",
+                ::core::stringify!($ty),
+                "` is larger than ",
+                ::core::stringify!($max_bytes),
+                " bytes"
+            ),
+        );
+    };
+}
+
 /// A [`ReArr`] is a fixed-size array with a variable number of elements.
 ///
 /// Create a new [`ReArr`] using the [`re_arr!`] macro.
@@ -79,58 +123,78 @@ macro_rules! re_arr {
 /// // Fill the last element on the stack, then allocate the next two items on the heap
 /// my_re_arr.extend([3, 4, 5]);
 /// ```
-pub struct ReArr<T, const N: usize> {
-    pub(crate) arr: [Option<T>; N],
+///
+/// ## Storage
+///
+/// Each element is kept in a [`Slot`] `S`, which defaults to [`Option<T>`] and works for any `T`.
+/// For `T: Default`, `ReArr<T, N, T>` stores the elements directly instead,
+/// so numbers and other plain types don't pay for a tag:
+///
+/// ```rust
+/// use combo_vec::ReArr;
+///
+/// // `new` is only for `Option<T>` slots, so create other arrays with `Default`
+/// let mut dense = ReArr::<u32, 64, u32>::default();
+/// dense.extend([1, 2]);
+/// assert_eq!(dense.as_slice(), &[1, 2]);
+/// assert!(size_of_val(&dense) < size_of::<ReArr<u32, 64>>());
+/// ```
+pub struct ReArr<T, const N: usize, S = Option<T>> {
+    pub(crate) arr: [S; N],
     arr_len: usize,
+    elem: PhantomData<T>,
 }
 
-impl<T: Clone, const N: usize> Clone for ReArr<T, N> {
+impl<T, S: Clone, const N: usize> Clone for ReArr<T, N, S> {
     #[inline]
     fn clone(&self) -> Self {
         Self {
             arr: self.arr.clone(),
             arr_len: self.arr_len,
+            elem: PhantomData,
         }
     }
 }
 
-impl<T: PartialOrd, const N: usize> PartialOrd for ReArr<T, N> {
+impl<T: PartialOrd, S: Slot<T>, const N: usize> PartialOrd for ReArr<T, N, S> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.iter().partial_cmp(other.iter())
     }
 }
 
-impl<T: Ord, const N: usize> Ord for ReArr<T, N> {
+impl<T: Ord, S: Slot<T>, const N: usize> Ord for ReArr<T, N, S> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         self.iter().cmp(other.iter())
     }
 }
 
-impl<T: PartialEq, const N: usize> PartialEq for ReArr<T, N> {
+impl<T: PartialEq, S: Slot<T>, const N: usize> PartialEq for ReArr<T, N, S> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.iter().eq(other.iter())
     }
 }
 
-impl<T: PartialEq + Eq, const N: usize> Eq for ReArr<T, N> {}
+impl<T: PartialEq + Eq, S: Slot<T>, const N: usize> Eq for ReArr<T, N, S> {}
 
-impl<T: Hash, const N: usize> Hash for ReArr<T, N> {
+impl<T: Hash, S: Slot<T>, const N: usize> Hash for ReArr<T, N, S> {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.iter().for_each(|x| x.hash(state));
     }
 }
 
-impl<T, const N: usize> Default for ReArr<T, N> {
+impl<T, S: Slot<T>, const N: usize> Default for ReArr<T, N, S> {
     #[inline]
     fn default() -> Self {
-        Self::new()
+        Self::with_empty_slots()
     }
 }
 
+// Slot trait methods can't be called in a `const fn`, so everything that touches a slot
+// and is `const` for `Option<T>` slots is implemented once per slot type.
 impl<T, const N: usize> ReArr<T, N> {
     const DEFAULT_ARR_VALUE: Option<T> = None;
 
@@ -153,6 +217,7 @@ impl<T, const N: usize> ReArr<T, N> {
         Self {
             arr: [Self::DEFAULT_ARR_VALUE; N],
             arr_len: 0,
+            elem: PhantomData,
         }
     }
 
@@ -184,7 +249,311 @@ impl<T, const N: usize> ReArr<T, N> {
             arr_len += 1;
         }
 
-        Self { arr, arr_len }
+        Self {
+            arr,
+            arr_len,
+            elem: PhantomData,
+        }
+    }
+
+    /// Remove the last element from the array and return it, or None if it is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.pop(), Some(3));
+    /// assert_eq!(my_re_arr.pop(), Some(2));
+    /// assert_eq!(my_re_arr.pop(), Some(1));
+    /// assert_eq!(my_re_arr.pop(), None);
+    /// ```
+    #[inline]
+    pub const fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.arr_len -= 1;
+            self.arr[self.arr_len].take()
+        }
+    }
+
+    /// Removes an element from the `ReArr` and returns it.
+    ///
+    /// The removed element is replaced by the last element of the `ReArr`.
+    ///
+    /// This does not preserve ordering, but is O(1). If you need to preserve the element order, use remove instead.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds, or if it is the last value.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.swap_remove(0), 1);
+    /// assert_eq!(my_re_arr.len(), 2);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec![3, 2]);
+    /// ```
+    #[inline]
+    pub const fn swap_remove(&mut self, index: usize) -> T {
+        let last_value = self.pop().unwrap();
+        self.arr[index].replace(last_value).unwrap()
+    }
+
+    /// Get the first element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.first(), Some(&1));
+    /// ```
+    #[inline]
+    pub const fn first(&self) -> Option<&T> {
+        if N == 0 {
+            None
+        } else {
+            self.arr[0].as_ref()
+        }
+    }
+
+    /// Get the first element as a mutable reference, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.first_mut(), Some(&mut 1));
+    /// ```
+    #[inline]
+    pub const fn first_mut(&mut self) -> Option<&mut T> {
+        if N == 0 {
+            None
+        } else {
+            self.arr[0].as_mut()
+        }
+    }
+
+    /// Get the last element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.last(), Some(&3));
+    /// ```
+    #[inline]
+    pub const fn last(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.arr[self.arr_len - 1].as_ref()
+        }
+    }
+
+    /// Get the last element as a mutable reference, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.last_mut(), Some(&mut 3));
+    /// ```
+    #[inline]
+    pub const fn last_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.arr[self.arr_len - 1].as_mut()
+        }
+    }
+}
+
+impl<E: Default, const N: usize> ReArr<E, N, E> {
+    /// Remove the last element from the array and return it, or None if it is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    ///
+    /// let mut my_re_arr = [1, 2].into_iter().collect::<ReArr<i32, 4, i32>>();
+    ///
+    /// assert_eq!(my_re_arr.pop(), Some(2));
+    /// assert_eq!(my_re_arr.pop(), Some(1));
+    /// assert_eq!(my_re_arr.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<E> {
+        if self.is_empty() {
+            None
+        } else {
+            self.arr_len -= 1;
+            Some(mem::take(&mut self.arr[self.arr_len]))
+        }
+    }
+
+    /// Removes an element from the `ReArr` and returns it.
+    ///
+    /// The removed element is replaced by the last element of the `ReArr`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    ///
+    /// let mut my_re_arr = [1, 2, 3].into_iter().collect::<ReArr<i32, 4, i32>>();
+    ///
+    /// assert_eq!(my_re_arr.swap_remove(0), 1);
+    /// assert_eq!(my_re_arr.as_slice(), &[3, 2]);
+    /// ```
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> E {
+        assert!(
+            index < self.arr_len,
+            "swap_remove index (is {index}) should be < len (is {})",
+            self.arr_len
+        );
+
+        self.arr_len -= 1;
+        self.arr.swap(index, self.arr_len);
+        mem::take(&mut self.arr[self.arr_len])
+    }
+
+    /// Get the first element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    ///
+    /// let my_re_arr = [1, 2, 3].into_iter().collect::<ReArr<i32, 4, i32>>();
+    /// assert_eq!(my_re_arr.first(), Some(&1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn first(&self) -> Option<&E> {
+        self.as_slice().first()
+    }
+
+    /// Get the first element as a mutable reference, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    ///
+    /// let mut my_re_arr = [1, 2, 3].into_iter().collect::<ReArr<i32, 4, i32>>();
+    /// assert_eq!(my_re_arr.first_mut(), Some(&mut 1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<&mut E> {
+        self.as_mut_slice().first_mut()
+    }
+
+    /// Get the last element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    ///
+    /// let my_re_arr = [1, 2, 3].into_iter().collect::<ReArr<i32, 4, i32>>();
+    /// assert_eq!(my_re_arr.last(), Some(&3));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn last(&self) -> Option<&E> {
+        self.as_slice().last()
+    }
+
+    /// Get the last element as a mutable reference, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    ///
+    /// let mut my_re_arr = [1, 2, 3].into_iter().collect::<ReArr<i32, 4, i32>>();
+    /// assert_eq!(my_re_arr.last_mut(), Some(&mut 3));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<&mut E> {
+        self.as_mut_slice().last_mut()
+    }
+
+    /// Get the elements as a slice.
+    ///
+    /// Only arrays that store their elements directly can do this.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    ///
+    /// let my_re_arr = [1, 2].into_iter().collect::<ReArr<u8, 4, u8>>();
+    /// assert_eq!(my_re_arr.as_slice(), &[1, 2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[E] {
+        &self.arr[..self.arr_len]
+    }
+
+    /// Get the elements as a mutable slice.
+    ///
+    /// Only arrays that store their elements directly can do this.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    ///
+    /// let mut my_re_arr = [2, 1].into_iter().collect::<ReArr<u8, 4, u8>>();
+    /// my_re_arr.as_mut_slice().sort_unstable();
+    /// assert_eq!(my_re_arr.as_slice(), &[1, 2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [E] {
+        &mut self.arr[..self.arr_len]
+    }
+}
+
+impl<T, S: Slot<T>, const N: usize> ReArr<T, N, S> {
+    // `new` is only available for `Option<T>` slots, where it's const.
+    fn with_empty_slots() -> Self {
+        Self {
+            arr: core::array::from_fn(|_| S::empty()),
+            arr_len: 0,
+            elem: PhantomData,
+        }
     }
 
     // Create a new [`ReArr`] from an iterator reference, taking up to N items
@@ -195,7 +564,7 @@ impl<T, const N: usize> ReArr<T, N> {
     // This is useful for ComboVec::from_iter, which needs to initialise both
     // a ReArr and a Vec.
     pub(crate) fn from_iter_ref(iter: &mut impl Iterator<Item = T>) -> Self {
-        let mut re_arr = Self::new();
+        let mut re_arr = Self::with_empty_slots();
         for _ in 0..N {
             if let Some(val) = iter.next() {
                 re_arr.push(val);
@@ -227,34 +596,10 @@ impl<T, const N: usize> ReArr<T, N> {
     /// ```
     #[inline]
     pub fn push(&mut self, val: T) {
-        self.arr[self.arr_len] = Some(val);
+        self.arr[self.arr_len] = S::fill(val);
         self.arr_len += 1;
     }
 
-    /// Remove the last element from the array and return it, or None if it is empty.
-    ///
-    /// ## Examples
-    ///
-    /// ```rust
-    /// use combo_vec::{re_arr, ReArr};
-    ///
-    /// let mut my_re_arr = re_arr![1, 2, 3; None];
-    ///
-    /// assert_eq!(my_re_arr.pop(), Some(3));
-    /// assert_eq!(my_re_arr.pop(), Some(2));
-    /// assert_eq!(my_re_arr.pop(), Some(1));
-    /// assert_eq!(my_re_arr.pop(), None);
-    /// ```
-    #[inline]
-    pub const fn pop(&mut self) -> Option<T> {
-        if self.is_empty() {
-            None
-        } else {
-            self.arr_len -= 1;
-            self.arr[self.arr_len].take()
-        }
-    }
-
     /// Get any element from the array as a reference, returning `None` if out of bounds.
     ///
     /// ## Examples
@@ -275,7 +620,7 @@ impl<T, const N: usize> ReArr<T, N> {
     #[must_use]
     #[inline]
     pub fn get(&self, idx: usize) -> Option<&T> {
-        self.arr.get(idx).and_then(|item| item.as_ref())
+        self.arr[..self.arr_len].get(idx).map(S::get)
     }
 
     /// Get any element from the array as a mutable reference, `None` if out of bounds.
@@ -299,7 +644,7 @@ impl<T, const N: usize> ReArr<T, N> {
     #[must_use]
     #[inline]
     pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
-        self.arr.get_mut(idx).and_then(|item| item.as_mut())
+        self.arr[..self.arr_len].get_mut(idx).map(S::get_mut)
     }
 
     /// How many elements are currently stored.
@@ -344,51 +689,271 @@ impl<T, const N: usize> ReArr<T, N> {
         N
     }
 
-    /// Reduce the number of elements to the given length.
-    ///
-    /// If `new_len` is greater than the current length, this has no effect.
+    /// Reduce the number of elements to the given length.
+    ///
+    /// If `new_len` is greater than the current length, this has no effect.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.len(), 3);
+    /// my_re_arr.truncate(2);
+    /// assert_eq!(my_re_arr.len(), 2);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.arr[len..].iter_mut().for_each(|x| *x = S::empty());
+        self.arr_len = self.arr_len.min(len);
+    }
+
+    /// Remove all elements from the array.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.len(), 3);
+    /// my_re_arr.clear();
+    /// assert_eq!(my_re_arr.len(), 0);
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.arr.iter_mut().for_each(|x| *x = S::empty());
+        self.arr_len = 0;
+    }
+
+    /// Retains only the elements specified by the predicate, preserving their order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3, 4; None];
+    ///
+    /// my_re_arr.retain(|x| x % 2 == 0);
+    /// assert_eq!(my_re_arr.len(), 2);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec![2, 4]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|x| f(x));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3, 4; None];
+    ///
+    /// my_re_arr.retain_mut(|x| {
+    ///     *x += 1;
+    ///     *x % 2 == 0
+    /// });
+    /// assert_eq!(my_re_arr.len(), 2);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec![2, 4]);
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let mut kept = 0;
+        for i in 0..self.arr_len {
+            let mut val = self.arr[i].take();
+            if f(&mut val) {
+                self.arr[kept] = S::fill(val);
+                kept += 1;
+            }
+        }
+
+        self.arr_len = kept;
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index > len`, or if the array is full.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// my_re_arr.insert(1, 4);
+    /// assert_eq!(my_re_arr.len(), 4);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec![1, 4, 2, 3]);
+    /// ```
+    pub fn insert(&mut self, index: usize, val: T) {
+        assert!(
+            index <= self.arr_len,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.arr_len
+        );
+        assert!(self.arr_len < N, "cannot insert into a full array");
+
+        self.arr[index..=self.arr_len].rotate_right(1);
+        self.arr[index] = S::fill(val);
+        self.arr_len += 1;
+    }
+
+    /// Removes and returns the element at position with a valid index, shifting all elements after it to the left.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// assert_eq!(my_re_arr.remove(1), 2);
+    /// assert_eq!(my_re_arr.len(), 2);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec![1, 3]);
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.arr_len,
+            "removal index (is {index}) should be < len (is {})",
+            self.arr_len
+        );
+
+        let val = self.arr[index].take();
+        self.arr[index..self.arr_len].rotate_left(1);
+        self.arr_len -= 1;
+
+        val
+    }
+
+    /// Swaps two elements in the `ReArr`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    ///
+    /// my_re_arr.swap(0, 2);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec![3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.arr_len && b < self.arr_len, "index out of bounds");
+        self.arr.swap(a, b);
+    }
+
+    /// Removes all but the first of consecutive elements that resolve to the same key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![10, 20, 21, 30, 20; None];
+    ///
+    /// my_re_arr.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(my_re_arr.len(), 4);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec![10, 20, 30, 20]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive elements that satisfy the given equality relation.
+    ///
+    /// The `same_bucket` function is passed the element being considered,
+    /// followed by the last element that was kept.
+    /// If it returns `true`, the element being considered is removed.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr!["foo", "bar", "Bar", "baz", "bar"; None];
+    ///
+    /// my_re_arr.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(my_re_arr.len(), 4);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec!["foo", "bar", "baz", "bar"]);
+    /// ```
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        if self.arr_len <= 1 {
+            return;
+        }
+
+        let mut kept = 1;
+        for i in 1..self.arr_len {
+            let mut val = self.arr[i].take();
+            if !same_bucket(&mut val, self.arr[kept - 1].get_mut()) {
+                self.arr[kept] = S::fill(val);
+                kept += 1;
+            }
+        }
+
+        self.arr_len = kept;
+    }
+
+    /// Check if there are no elements.
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    /// let my_re_arr = re_arr![1, 2, 3; None];
+    /// assert_eq!(my_re_arr.is_empty(), false);
     ///
-    /// assert_eq!(my_re_arr.len(), 3);
-    /// my_re_arr.truncate(2);
-    /// assert_eq!(my_re_arr.len(), 2);
+    /// let empty_re_arr = ReArr::<i32, 3>::new();
+    /// assert_eq!(empty_re_arr.is_empty(), true);
     /// ```
     #[inline]
-    pub fn truncate(&mut self, len: usize) {
-        self.arr[len..].iter_mut().for_each(|x| *x = None);
-        self.arr_len = self.arr_len.min(len);
+    pub const fn is_empty(&self) -> bool {
+        self.arr_len == 0
     }
 
-    /// Remove all elements from the array.
+    /// Get an iterator over the elements of the array.
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    /// let my_re_arr = re_arr![1, 2, 3; None];
     ///
-    /// assert_eq!(my_re_arr.len(), 3);
-    /// my_re_arr.clear();
-    /// assert_eq!(my_re_arr.len(), 0);
+    /// assert_eq!(my_re_arr.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
     /// ```
     #[inline]
-    pub fn clear(&mut self) {
-        self.arr.iter_mut().for_each(|x| *x = None);
-        self.arr_len = 0;
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.arr[..self.arr_len].iter().map(S::get)
     }
 
-    /// Removes and returns the element at position with a valid index, shifting all elements after it to the left.
-    ///
-    /// ## Panics
-    ///
-    /// Panics if `index` is out of bounds.
+    /// Get an iterator over the elements of the array, returning mutable references.
     ///
     /// ## Examples
     ///
@@ -397,73 +962,67 @@ impl<T, const N: usize> ReArr<T, N> {
     ///
     /// let mut my_re_arr = re_arr![1, 2, 3; None];
     ///
-    /// assert_eq!(my_re_arr.remove(1), 2);
-    /// assert_eq!(my_re_arr.len(), 2);
-    /// #[cfg(feature = "alloc")]
-    /// assert_eq!(my_re_arr.to_vec(), vec![1, 3]);
+    /// assert_eq!(my_re_arr.iter_mut().collect::<Vec<_>>(), vec![&mut 1, &mut 2, &mut 3]);
     /// ```
     #[inline]
-    pub fn remove(&mut self, index: usize) -> T {
-        let val = self.arr[index].take().unwrap();
-
-        for i in index..self.arr_len - 1 {
-            self.arr[i] = self.arr[i + 1].take();
-        }
-
-        self.arr_len -= 1;
-
-        val
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.arr[..self.arr_len].iter_mut().map(S::get_mut)
     }
 
-    /// Removes an element from the `ReArr` and returns it.
-    ///
-    /// The removed element is replaced by the last element of the `ReArr`.
-    ///
-    /// This does not preserve ordering, but is O(1). If you need to preserve the element order, use remove instead.
+    /// Extend this array with all the elements from the given iterator.
     ///
     /// ## Panics
     ///
-    /// Panics if `index` is out of bounds, or if it is the last value.
+    /// Panics if the iterator tries to push more elements than the internal array can hold.
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    /// let mut my_re_arr = re_arr![1, 2, 3; None, None, None];
+    /// assert_eq!(my_re_arr.len(), 3);
+    /// assert_eq!(my_re_arr.capacity(), 6);
     ///
-    /// assert_eq!(my_re_arr.swap_remove(0), 1);
-    /// assert_eq!(my_re_arr.len(), 2);
-    /// #[cfg(feature = "alloc")]
-    /// assert_eq!(my_re_arr.to_vec(), vec![3, 2]);
+    /// my_re_arr.extend([4, 5, 6]);
+    /// assert_eq!(my_re_arr.len(), 6);
     /// ```
     #[inline]
-    pub const fn swap_remove(&mut self, index: usize) -> T {
-        let last_value = self.pop().unwrap();
-        self.arr[index].replace(last_value).unwrap()
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| self.push(x));
     }
 
-    /// Get the first element, returning `None` if there are no elements.
+    /// Clone and append every element of a slice, filling the free slots in one pass.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the elements don't fit in the internal array.
+    /// See [`ReArr::try_extend_from_slice`] for a non-panicking version.
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let my_re_arr = re_arr![1, 2, 3; None];
-    ///
-    /// assert_eq!(my_re_arr.first(), Some(&1));
+    /// let mut my_re_arr = re_arr![1, 2, 3; None, None];
+    /// my_re_arr.extend_from_slice(&[4, 5]);
+    /// assert_eq!(my_re_arr, re_arr![1, 2, 3, 4, 5]);
     /// ```
     #[inline]
-    pub const fn first(&self) -> Option<&T> {
-        if N == 0 {
-            None
-        } else {
-            self.arr[0].as_ref()
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        if let Err(err) = self.try_extend_from_slice(other) {
+            panic!("{err}");
         }
     }
 
-    /// Get the first element as a mutable reference, returning `None` if there are no elements.
+    /// Clone and append every element of a slice, filling the free slots in one pass.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the elements don't fit in the internal array, in which case nothing is appended.
     ///
     /// ## Examples
     ///
@@ -472,38 +1031,70 @@ impl<T, const N: usize> ReArr<T, N> {
     ///
     /// let mut my_re_arr = re_arr![1, 2, 3; None];
     ///
-    /// assert_eq!(my_re_arr.first_mut(), Some(&mut 1));
+    /// let err = my_re_arr.try_extend_from_slice(&[4, 5]).unwrap_err();
+    /// assert_eq!(err.required(), 5);
+    /// assert_eq!(my_re_arr.len(), 3);
+    ///
+    /// assert!(my_re_arr.try_extend_from_slice(&[4]).is_ok());
+    /// assert_eq!(my_re_arr.len(), 4);
     /// ```
-    #[inline]
-    pub const fn first_mut(&mut self) -> Option<&mut T> {
-        if N == 0 {
-            None
-        } else {
-            self.arr[0].as_mut()
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        let required = self.arr_len + other.len();
+        if required > N {
+            return Err(CapacityError::new(required, N));
         }
+
+        for (slot, val) in self.arr[self.arr_len..required].iter_mut().zip(other) {
+            *slot = S::fill(val.clone());
+        }
+
+        self.arr_len = required;
+        Ok(())
     }
 
-    /// Get the last element, returning `None` if there are no elements.
+    /// Replace the contents of this array with clones of the elements of a slice.
+    ///
+    /// Elements that are already in the array are overwritten with [`Clone::clone_from`],
+    /// so they can reuse any resources they own.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the elements don't fit in the internal array.
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let my_re_arr = re_arr![1, 2, 3; None];
-    ///
-    /// assert_eq!(my_re_arr.last(), Some(&3));
+    /// let mut my_re_arr = re_arr![String::from("a"), String::from("b"), String::from("c")];
+    /// my_re_arr.clone_from_slice(&[String::from("x")]);
+    /// assert_eq!(my_re_arr.len(), 1);
+    /// assert_eq!(my_re_arr[0], "x");
     /// ```
     #[inline]
-    pub const fn last(&self) -> Option<&T> {
-        if self.is_empty() {
-            None
-        } else {
-            self.arr[self.arr_len - 1].as_ref()
-        }
+    pub fn clone_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        self.truncate(src.len());
+        let (init, tail) = src.split_at(self.arr_len);
+        self.iter_mut()
+            .zip(init)
+            .for_each(|(dst, src)| dst.clone_from(src));
+        self.extend_from_slice(tail);
     }
 
-    /// Get the last element as a mutable reference, returning `None` if there are no elements.
+    /// Splits the [`ReArr`] into two at the given index.
+    ///
+    /// Returns a new [`ReArr`] containing the elements in the range `[at, len)`,
+    /// leaving the elements `[0, at)` in this one.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `at > len`.
     ///
     /// ## Examples
     ///
@@ -511,89 +1102,177 @@ impl<T, const N: usize> ReArr<T, N> {
     /// use combo_vec::{re_arr, ReArr};
     ///
     /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    /// let other = my_re_arr.split_off(1);
     ///
-    /// assert_eq!(my_re_arr.last_mut(), Some(&mut 3));
+    /// assert_eq!(my_re_arr.len(), 1);
+    /// assert_eq!(other.len(), 2);
+    /// assert_eq!(other.capacity(), 4);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(other.to_vec(), vec![2, 3]);
     /// ```
-    #[inline]
-    pub const fn last_mut(&mut self) -> Option<&mut T> {
-        if self.is_empty() {
-            None
-        } else {
-            self.arr[self.arr_len - 1].as_mut()
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(
+            at <= self.arr_len,
+            "`at` split index (is {at}) should be <= len (is {})",
+            self.arr_len
+        );
+
+        let mut other = Self::with_empty_slots();
+        for (slot, val) in other.arr.iter_mut().zip(&mut self.arr[at..self.arr_len]) {
+            mem::swap(slot, val);
         }
+
+        other.arr_len = self.arr_len - at;
+        self.arr_len = at;
+
+        other
     }
 
-    /// Check if there are no elements.
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// `other` may have a different capacity than `self`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the combined number of elements is greater than the capacity of `self`.
+    /// See [`ReArr::try_append`] for a non-panicking version.
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let my_re_arr = re_arr![1, 2, 3; None];
-    /// assert_eq!(my_re_arr.is_empty(), false);
+    /// let mut my_re_arr = re_arr![1, 2, 3; None, None];
+    /// let mut other = re_arr![4, 5];
+    /// my_re_arr.append(&mut other);
     ///
-    /// let empty_re_arr = ReArr::<i32, 3>::new();
-    /// assert_eq!(empty_re_arr.is_empty(), true);
+    /// assert_eq!(my_re_arr.len(), 5);
+    /// assert!(other.is_empty());
     /// ```
     #[inline]
-    pub const fn is_empty(&self) -> bool {
-        self.arr_len == 0
+    pub fn append<const M: usize>(&mut self, other: &mut ReArr<T, M, S>) {
+        if let Err(err) = self.try_append(other) {
+            panic!("{err}");
+        }
     }
 
-    /// Get an iterator over the elements of the array.
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// `other` may have a different capacity than `self`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the combined number of elements is greater than the capacity of `self`,
+    /// in which case neither array is modified.
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let my_re_arr = re_arr![1, 2, 3; None];
+    /// let mut my_re_arr = re_arr![1, 2, 3; None];
     ///
-    /// assert_eq!(my_re_arr.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// let mut other = re_arr![4, 5];
+    /// assert!(my_re_arr.try_append(&mut other).is_err());
+    /// assert_eq!(my_re_arr.len(), 3);
+    /// assert_eq!(other.len(), 2);
+    ///
+    /// let mut other = re_arr![4];
+    /// assert!(my_re_arr.try_append(&mut other).is_ok());
+    /// assert_eq!(my_re_arr.len(), 4);
+    /// assert!(other.is_empty());
     /// ```
-    #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
-        self.arr.iter().flatten()
+    pub fn try_append<const M: usize>(
+        &mut self,
+        other: &mut ReArr<T, M, S>,
+    ) -> Result<(), CapacityError> {
+        let required = self.arr_len + other.arr_len;
+        if required > N {
+            return Err(CapacityError::new(required, N));
+        }
+
+        for (slot, val) in self.arr[self.arr_len..]
+            .iter_mut()
+            .zip(&mut other.arr[..other.arr_len])
+        {
+            mem::swap(slot, val);
+        }
+
+        self.arr_len = required;
+        other.arr_len = 0;
+
+        Ok(())
     }
 
-    /// Get an iterator over the elements of the array, returning mutable references.
+    /// Move the elements into a [`ReArr`] that can hold at least as many elements as this one.
+    ///
+    /// Trying to convert into a smaller capacity is a compile-time error,
+    /// use [`ReArr::try_into_capacity`] instead.
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let mut my_re_arr = re_arr![1, 2, 3; None];
+    /// let my_re_arr = re_arr![1, 2, 3; None];
+    /// let bigger: ReArr<i32, 8> = my_re_arr.into_capacity();
     ///
-    /// assert_eq!(my_re_arr.iter_mut().collect::<Vec<_>>(), vec![&mut 1, &mut 2, &mut 3]);
+    /// assert_eq!(bigger.len(), 3);
+    /// assert_eq!(bigger.capacity(), 8);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let my_re_arr = re_arr![1; None, None];
+    /// let smaller: ReArr<i32, 2> = my_re_arr.into_capacity();
     /// ```
+    #[doc(alias = "resize_capacity")]
+    #[must_use]
     #[inline]
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
-        self.arr.iter_mut().flatten()
+    pub fn into_capacity<const M: usize>(mut self) -> ReArr<T, M, S> {
+        const {
+            assert!(
+                M >= N,
+                "the new capacity must not be smaller than the current one"
+            );
+        }
+
+        let mut other = ReArr::with_empty_slots();
+        other.append(&mut self);
+        other
     }
 
-    /// Extend this array with all the elements from the given iterator.
+    /// Move the elements into a [`ReArr`] of any capacity.
     ///
-    /// ## Panics
+    /// ## Errors
     ///
-    /// Panics if the iterator tries to push more elements than the internal array can hold.
+    /// Returns a [`CapacityError`] if there are more than `M` elements.
+    /// The original [`ReArr`] can be recovered with [`CapacityError::into_inner`].
     ///
     /// ## Examples
     ///
     /// ```rust
     /// use combo_vec::{re_arr, ReArr};
     ///
-    /// let mut my_re_arr = re_arr![1, 2, 3; None, None, None];
-    /// assert_eq!(my_re_arr.len(), 3);
-    /// assert_eq!(my_re_arr.capacity(), 6);
+    /// let my_re_arr = re_arr![1, 2, 3; None, None];
     ///
-    /// my_re_arr.extend([4, 5, 6]);
-    /// assert_eq!(my_re_arr.len(), 6);
+    /// let smaller: ReArr<i32, 3> = my_re_arr.try_into_capacity().unwrap();
+    /// assert_eq!(smaller.len(), 3);
+    /// assert_eq!(smaller.capacity(), 3);
+    ///
+    /// assert!(smaller.try_into_capacity::<2>().is_err());
     /// ```
     #[inline]
-    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        iter.into_iter().for_each(|x| self.push(x));
+    pub fn try_into_capacity<const M: usize>(
+        mut self,
+    ) -> Result<ReArr<T, M, S>, CapacityError<Self>> {
+        let mut other = ReArr::with_empty_slots();
+        match other.try_append(&mut self) {
+            Ok(()) => Ok(other),
+            Err(err) => Err(err.with_inner(self)),
+        }
     }
 
     /// Get this [`ReArr`] transformed into a [`Vec`].
@@ -610,7 +1289,12 @@ impl<T, const N: usize> ReArr<T, N> {
     #[cfg(feature = "alloc")]
     #[inline]
     pub fn into_vec(self) -> Vec<T> {
-        self.into_iter().collect()
+        let len = self.arr_len;
+        self.arr
+            .into_iter()
+            .take(len)
+            .map(|mut slot| slot.take())
+            .collect()
     }
 
     /// Get this [`ReArr`] represented as a [`Vec`], borrowing data instead of moving it.
@@ -631,7 +1315,7 @@ impl<T, const N: usize> ReArr<T, N> {
     }
 }
 
-impl<T: Clone, const N: usize> ReArr<T, N> {
+impl<T: Clone, S: Slot<T>, const N: usize> ReArr<T, N, S> {
     /// Get this [`ReArr`] represented as a [`Vec`].
     ///
     /// ## Examples
@@ -686,9 +1370,11 @@ impl<T: Clone, const N: usize> ReArr<T, N> {
         );
 
         if new_len > self.arr_len {
-            self.arr[self.arr_len..new_len].fill(Some(val));
+            for slot in &mut self.arr[self.arr_len..new_len] {
+                *slot = S::fill(val.clone());
+            }
         } else {
-            self.arr[new_len..].fill(None);
+            self.arr[new_len..].iter_mut().for_each(|x| *x = S::empty());
         }
 
         self.arr_len = new_len;
@@ -729,17 +1415,73 @@ impl<T: Clone, const N: usize> ReArr<T, N> {
         );
 
         if new_len > self.arr_len {
-            self.arr[self.arr_len..new_len].fill(Some(f()));
+            for slot in &mut self.arr[self.arr_len..new_len] {
+                *slot = S::fill(f());
+            }
         } else {
-            self.arr[new_len..].fill(None);
+            self.arr[new_len..].iter_mut().for_each(|x| *x = S::empty());
         }
 
         self.arr_len = new_len;
     }
+
+    /// Create a new [`ReArr`] by cloning and joining together the elements of every part, in order.
+    ///
+    /// The parts may have a different capacity than the result.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the total number of elements is greater than `N`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let parts = [re_arr![1, 2; None], re_arr![3; None, None]];
+    ///
+    /// let joined = ReArr::<i32, 4>::try_concat(&parts).unwrap();
+    /// assert_eq!(joined.len(), 3);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(joined.to_vec(), vec![1, 2, 3]);
+    ///
+    /// assert!(ReArr::<i32, 2>::try_concat(&parts).is_err());
+    /// ```
+    pub fn try_concat<const M: usize>(parts: &[ReArr<T, M, S>]) -> Result<Self, CapacityError> {
+        let required = parts.iter().map(ReArr::len).sum();
+        if required > N {
+            return Err(CapacityError::new(required, N));
+        }
+
+        Ok(parts.iter().flat_map(ReArr::iter).cloned().collect())
+    }
+}
+
+impl<T: PartialEq, S: Slot<T>, const N: usize> ReArr<T, N, S> {
+    /// Removes consecutive repeated elements.
+    ///
+    /// If the [`ReArr`] is sorted, this removes all duplicates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArr};
+    ///
+    /// let mut my_re_arr = re_arr![1, 2, 2, 3, 2; None];
+    ///
+    /// my_re_arr.dedup();
+    /// assert_eq!(my_re_arr.len(), 4);
+    /// #[cfg(feature = "alloc")]
+    /// assert_eq!(my_re_arr.to_vec(), vec![1, 2, 3, 2]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
 }
 
 #[cfg(feature = "alloc")]
-impl<T: ToString, const N: usize> ReArr<T, N> {
+impl<T: ToString, S: Slot<T>, const N: usize> ReArr<T, N, S> {
     /// Joins the [`ReArr`] into a string with a separator.
     ///
     /// ## Examples
@@ -764,19 +1506,19 @@ impl<T: ToString, const N: usize> ReArr<T, N> {
     }
 }
 
-impl<T, const N: usize> ops::Index<usize> for ReArr<T, N> {
+impl<T, S: Slot<T>, const N: usize> ops::Index<usize> for ReArr<T, N, S> {
     type Output = T;
 
     #[inline]
     fn index(&self, idx: usize) -> &Self::Output {
-        self.arr[idx].as_ref().unwrap()
+        self.arr[..self.arr_len][idx].get()
     }
 }
 
-impl<T, const N: usize> ops::IndexMut<usize> for ReArr<T, N> {
+impl<T, S: Slot<T>, const N: usize> ops::IndexMut<usize> for ReArr<T, N, S> {
     #[inline]
     fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
-        self.arr[idx].as_mut().unwrap()
+        self.arr[..self.arr_len][idx].get_mut()
     }
 }
 
@@ -790,14 +1532,38 @@ impl<T, const N: usize> IntoIterator for ReArr<T, N> {
     }
 }
 
-impl<T, const N: usize> FromIterator<T> for ReArr<T, N> {
+impl<E: Default, const N: usize> IntoIterator for ReArr<E, N, E> {
+    type Item = E;
+    type IntoIter = Take<ArrayIter<E, N>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.arr.into_iter().take(self.arr_len)
+    }
+}
+
+impl<E: Default, const N: usize> From<ReArr<E, N>> for ReArr<E, N, E> {
+    #[inline]
+    fn from(arr: ReArr<E, N>) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<T: Default, const N: usize> From<ReArr<T, N, T>> for ReArr<T, N> {
+    #[inline]
+    fn from(arr: ReArr<T, N, T>) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<T, S: Slot<T>, const N: usize> FromIterator<T> for ReArr<T, N, S> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Self::from_iter_ref(&mut iter.into_iter())
     }
 }
 
-impl<T: Debug, const N: usize> Debug for ReArr<T, N> {
+impl<T, S: Debug, const N: usize> Debug for ReArr<T, N, S> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("ReArr")
@@ -807,9 +1573,46 @@ impl<T: Debug, const N: usize> Debug for ReArr<T, N> {
     }
 }
 
-impl<T: Debug, const N: usize> Display for ReArr<T, N> {
+impl<T: Debug, S: Slot<T>, const N: usize> Display for ReArr<T, N, S> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.debug_list().entries(self.arr.iter().flatten()).finish()
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<S: Slot<u8>, const N: usize> fmt::Write for ReArr<u8, N, S> {
+    /// Append the UTF-8 bytes of the string.
+    ///
+    /// Returns an error without writing anything if the string doesn't fit.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    /// use core::fmt::Write;
+    ///
+    /// let mut buf = ReArr::<u8, 8>::new();
+    /// write!(buf, "id={}", 42).unwrap();
+    /// assert_eq!(buf.iter().copied().collect::<Vec<_>>(), b"id=42");
+    ///
+    /// assert!(write!(buf, "1234").is_err());
+    /// assert_eq!(buf.len(), 5);
+    /// ```
+    #[inline]
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        let required = self.arr_len + s.len();
+        if required > N {
+            return Err(fmt::Error);
+        }
+
+        for (slot, &byte) in self.arr[self.arr_len..required]
+            .iter_mut()
+            .zip(s.as_bytes())
+        {
+            *slot = S::fill(byte);
+        }
+
+        self.arr_len = required;
+        Ok(())
     }
 }
@@ -0,0 +1,171 @@
+use crate::{CapacityError, ReArr};
+use core::convert::Infallible;
+use embedded_io::{Error, ErrorKind, ErrorType, Read, Write};
+
+impl Error for CapacityError {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::WriteZero
+    }
+}
+
+impl<const N: usize> ErrorType for ReArr<u8, N> {
+    type Error = CapacityError;
+}
+
+impl<const N: usize> Write for ReArr<u8, N> {
+    /// Append as many bytes as fit, returning how many were written.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the array is already full and `buf` isn't empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReArr;
+    /// use embedded_io::Write;
+    ///
+    /// let mut buf = ReArr::<u8, 4>::new();
+    /// assert_eq!(buf.write(&[1, 2, 3, 4, 5]), Ok(4));
+    /// assert!(buf.write(&[5]).is_err());
+    /// assert!(buf.write_all(&[]).is_ok());
+    /// ```
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let len = self.len();
+        if len == N && !buf.is_empty() {
+            return Err(CapacityError::new(len + buf.len(), N));
+        }
+
+        let amt = buf.len().min(N - len);
+        buf[..amt].iter().for_each(|&byte| self.push(byte));
+
+        Ok(amt)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// A reader over the bytes of a [`ReArr<u8, N>`] that implements [`embedded_io::Read`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_arr, ReArrReader};
+/// use embedded_io::Read;
+///
+/// let frame = re_arr![0xAAu8, 1, 2, 3; None, None];
+/// let mut reader = ReArrReader::new(&frame);
+///
+/// let mut header = [0];
+/// reader.read_exact(&mut header).unwrap();
+/// assert_eq!(header, [0xAA]);
+///
+/// let mut body = [0; 8];
+/// assert_eq!(reader.read(&mut body), Ok(3));
+/// assert_eq!(&body[..3], &[1, 2, 3]);
+/// assert_eq!(reader.read(&mut body), Ok(0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ReArrReader<'a, const N: usize> {
+    arr: &'a ReArr<u8, N>,
+    pos: usize,
+}
+
+impl<'a, const N: usize> ReArrReader<'a, N> {
+    /// Create a new reader at the start of the given bytes.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArrReader};
+    ///
+    /// let frame = re_arr![1u8, 2, 3];
+    /// let reader = ReArrReader::new(&frame);
+    /// assert_eq!(reader.remaining(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(arr: &'a ReArr<u8, N>) -> Self {
+        Self { arr, pos: 0 }
+    }
+
+    /// How many bytes have been read.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArrReader};
+    /// use embedded_io::Read;
+    ///
+    /// let frame = re_arr![1u8, 2, 3];
+    /// let mut reader = ReArrReader::new(&frame);
+    /// reader.read_exact(&mut [0; 2]).unwrap();
+    /// assert_eq!(reader.position(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// How many bytes are left to read.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArrReader};
+    /// use embedded_io::Read;
+    ///
+    /// let frame = re_arr![1u8, 2, 3];
+    /// let mut reader = ReArrReader::new(&frame);
+    /// reader.read_exact(&mut [0; 2]).unwrap();
+    /// assert_eq!(reader.remaining(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.arr.len().saturating_sub(self.pos)
+    }
+
+    /// Get the underlying bytes.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReArrReader};
+    ///
+    /// let frame = re_arr![1u8, 2, 3];
+    /// let reader = ReArrReader::new(&frame);
+    /// assert_eq!(reader.get_ref(), &frame);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn get_ref(&self) -> &'a ReArr<u8, N> {
+        self.arr
+    }
+}
+
+impl<const N: usize> ErrorType for ReArrReader<'_, N> {
+    type Error = Infallible;
+}
+
+impl<const N: usize> Read for ReArrReader<'_, N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let amt = buf.len().min(self.remaining());
+        for (dst, src) in buf
+            .iter_mut()
+            .zip(self.arr.arr[self.pos..self.pos + amt].iter().flatten())
+        {
+            *dst = *src;
+        }
+        self.pos += amt;
+
+        Ok(amt)
+    }
+}
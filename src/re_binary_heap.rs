@@ -0,0 +1,453 @@
+use crate::{CapacityError, ReArr};
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem,
+    ops::{Deref, DerefMut},
+};
+
+/// A max-heap priority queue that stores up to `N` elements on the stack.
+///
+/// This mirrors `alloc::collections::BinaryHeap`,
+/// where [`ReBinaryHeap::pop`] always returns the greatest element.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_arr, ReBinaryHeap};
+///
+/// const EMPTY: ReBinaryHeap<i32, 4> = ReBinaryHeap::new();
+///
+/// let mut heap = EMPTY;
+/// heap.push(3);
+/// heap.push(7);
+/// heap.push(1);
+/// assert_eq!(heap.peek(), Some(&7));
+///
+/// assert_eq!(heap.pop(), Some(7));
+/// assert_eq!(heap.pop(), Some(3));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.pop(), None);
+///
+/// // Build a heap in O(n) from an existing ReArr
+/// let heap = ReBinaryHeap::from(re_arr![2, 9, 4]);
+/// assert_eq!(heap.into_sorted_arr(), re_arr![2, 4, 9]);
+/// ```
+pub struct ReBinaryHeap<T, const N: usize> {
+    data: ReArr<T, N>,
+}
+
+impl<T: Clone, const N: usize> Clone for ReBinaryHeap<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ReBinaryHeap<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ReBinaryHeap<T, N> {
+    /// Create a new, empty [`ReBinaryHeap`] with room for `N` elements on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let heap = ReBinaryHeap::<i32, 4>::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { data: ReArr::new() }
+    }
+
+    /// How many elements are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let heap: ReBinaryHeap<i32, 2> = [1, 2].into_iter().collect();
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let heap = ReBinaryHeap::<i32, 1>::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// How many elements can be stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let heap = ReBinaryHeap::<i32, 3>::new();
+    /// assert_eq!(heap.capacity(), 3);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Get the greatest element, or `None` if the heap is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let mut heap = ReBinaryHeap::<i32, 2>::new();
+    /// assert_eq!(heap.peek(), None);
+    /// heap.push(1);
+    /// heap.push(5);
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Remove all elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let mut heap: ReBinaryHeap<i32, 2> = [1, 2].into_iter().collect();
+    /// heap.clear();
+    /// assert!(heap.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Remove all elements, returning them in arbitrary order.
+    ///
+    /// The heap is empty once this returns, even if the iterator isn't fully consumed.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let mut heap: ReBinaryHeap<i32, 3> = [1, 2, 3].into_iter().collect();
+    /// assert_eq!(heap.drain().count(), 3);
+    /// assert!(heap.is_empty());
+    /// ```
+    #[inline]
+    pub fn drain(&mut self) -> <ReArr<T, N> as IntoIterator>::IntoIter {
+        mem::take(&mut self.data).into_iter()
+    }
+
+    /// Get an iterator over the elements, in arbitrary order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let heap: ReBinaryHeap<i32, 3> = [1, 3, 2].into_iter().collect();
+    /// assert_eq!(heap.iter().sum::<i32>(), 6);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.data.iter()
+    }
+
+    /// Get the underlying [`ReArr`], in arbitrary order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let heap: ReBinaryHeap<i32, 3> = [1, 3, 2].into_iter().collect();
+    /// assert_eq!(heap.into_arr().len(), 3);
+    /// ```
+    #[inline]
+    pub fn into_arr(self) -> ReArr<T, N> {
+        self.data
+    }
+}
+
+impl<T: Ord, const N: usize> ReBinaryHeap<T, N> {
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if self.data[idx] <= self.data[parent] {
+                break;
+            }
+
+            self.data.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down_range(&mut self, mut idx: usize, end: usize) {
+        loop {
+            let mut child = 2 * idx + 1;
+            if child >= end {
+                break;
+            }
+
+            if child + 1 < end && self.data[child] < self.data[child + 1] {
+                child += 1;
+            }
+
+            if self.data[idx] >= self.data[child] {
+                break;
+            }
+
+            self.data.swap(idx, child);
+            idx = child;
+        }
+    }
+
+    /// Add an element to the heap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the heap is full.
+    /// See [`ReBinaryHeap::try_push`] for a non-panicking version.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let mut heap = ReBinaryHeap::<i32, 2>::new();
+    /// heap.push(1);
+    /// heap.push(3);
+    /// assert_eq!(heap.peek(), Some(&3));
+    /// ```
+    #[inline]
+    pub fn push(&mut self, val: T) {
+        if let Err(err) = self.try_push(val) {
+            panic!("{err}");
+        }
+    }
+
+    /// Add an element to the heap.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the heap is full.
+    /// The element can be recovered with [`CapacityError::into_inner`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let mut heap = ReBinaryHeap::<i32, 1>::new();
+    /// assert_eq!(heap.try_push(1), Ok(()));
+    /// assert_eq!(heap.try_push(2).unwrap_err().into_inner(), 2);
+    /// ```
+    pub fn try_push(&mut self, val: T) -> Result<(), CapacityError<T>> {
+        if self.data.len() == N {
+            return Err(CapacityError::new(N + 1, N).with_inner(val));
+        }
+
+        self.data.push(val);
+        self.sift_up(self.data.len() - 1);
+        Ok(())
+    }
+
+    /// Remove and return the greatest element, or `None` if the heap is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let mut heap: ReBinaryHeap<i32, 2> = [1, 3].into_iter().collect();
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.data.len();
+        if len > 1 {
+            self.data.swap(0, len - 1);
+        }
+
+        let val = self.data.pop()?;
+        self.sift_down_range(0, self.data.len());
+        Some(val)
+    }
+
+    /// Get a mutable reference to the greatest element, or `None` if the heap is empty.
+    ///
+    /// The heap is restored once the returned guard is dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{ReBinaryHeap, ReBinaryHeapPeekMut};
+    ///
+    /// let mut heap: ReBinaryHeap<i32, 3> = [1, 5, 3].into_iter().collect();
+    /// if let Some(mut top) = heap.peek_mut() {
+    ///     *top = 0;
+    /// }
+    /// assert_eq!(heap.peek(), Some(&3));
+    ///
+    /// let top = heap.peek_mut().unwrap();
+    /// assert_eq!(ReBinaryHeapPeekMut::pop(top), 3);
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    #[inline]
+    pub const fn peek_mut(&mut self) -> Option<ReBinaryHeapPeekMut<'_, T, N>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(ReBinaryHeapPeekMut { heap: self })
+        }
+    }
+
+    /// Get the elements as a [`ReArr`] sorted in ascending order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_arr, ReBinaryHeap};
+    ///
+    /// let heap: ReBinaryHeap<i32, 4> = [4, 1, 3, 2].into_iter().collect();
+    /// assert_eq!(heap.into_sorted_arr(), re_arr![1, 2, 3, 4]);
+    /// ```
+    pub fn into_sorted_arr(mut self) -> ReArr<T, N> {
+        for end in (1..self.data.len()).rev() {
+            self.data.swap(0, end);
+            self.sift_down_range(0, end);
+        }
+
+        self.data
+    }
+
+    /// Add all elements of an iterator to the heap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the heap becomes full.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReBinaryHeap;
+    ///
+    /// let mut heap = ReBinaryHeap::<i32, 3>::new();
+    /// heap.extend([1, 4, 2]);
+    /// assert_eq!(heap.peek(), Some(&4));
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| self.push(val));
+    }
+}
+
+/// A guard that holds a mutable reference to the greatest element of a [`ReBinaryHeap`].
+///
+/// Returned by [`ReBinaryHeap::peek_mut`].
+pub struct ReBinaryHeapPeekMut<'a, T: Ord, const N: usize> {
+    heap: &'a mut ReBinaryHeap<T, N>,
+}
+
+impl<T: Ord, const N: usize> ReBinaryHeapPeekMut<'_, T, N> {
+    /// Remove the peeked element from the heap and return it.
+    #[must_use]
+    #[inline]
+    pub fn pop(this: Self) -> T {
+        let val = this.heap.pop();
+        drop(this);
+        // the guard is only handed out for a non-empty heap
+        val.unwrap_or_else(|| unreachable!())
+    }
+}
+
+impl<T: Ord, const N: usize> Deref for ReBinaryHeapPeekMut<'_, T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.heap.data[0]
+    }
+}
+
+impl<T: Ord, const N: usize> DerefMut for ReBinaryHeapPeekMut<'_, T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.heap.data[0]
+    }
+}
+
+impl<T: Ord, const N: usize> Drop for ReBinaryHeapPeekMut<'_, T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        self.heap.sift_down_range(0, self.heap.len());
+    }
+}
+
+impl<T: Ord, const N: usize> From<ReArr<T, N>> for ReBinaryHeap<T, N> {
+    /// Build a heap from the elements in O(n) time.
+    fn from(data: ReArr<T, N>) -> Self {
+        let mut heap = Self { data };
+        let len = heap.len();
+        for idx in (0..len / 2).rev() {
+            heap.sift_down_range(idx, len);
+        }
+
+        heap
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ReBinaryHeap<T, N> {
+    type Item = T;
+    type IntoIter = <ReArr<T, N> as IntoIterator>::IntoIter;
+
+    /// Get an iterator over the elements, in arbitrary order.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for ReBinaryHeap<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<ReArr<T, N>>())
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ReBinaryHeap<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
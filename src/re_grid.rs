@@ -0,0 +1,511 @@
+use crate::ReArr;
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    ops,
+};
+
+/// The offsets of the four orthogonal neighbors of a cell.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+/// The offsets of all eight neighbors of a cell.
+const WITH_DIAGONALS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Get the in-bounds coordinates of the cells around `(x, y)`, optionally including the diagonal ones.
+///
+/// This is shared with [`ComboGrid`](crate::ComboGrid), so it can't be private to this module.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn neighbor_coords(
+    (x, y): (usize, usize),
+    width: usize,
+    height: usize,
+    diagonals: bool,
+) -> impl Iterator<Item = (usize, usize)> {
+    let offsets: &[(isize, isize)] = if diagonals {
+        &WITH_DIAGONALS
+    } else {
+        &ORTHOGONAL
+    };
+
+    offsets.iter().filter_map(move |&(dx, dy)| {
+        let nx = x.checked_add_signed(dx)?;
+        let ny = y.checked_add_signed(dy)?;
+        (nx < width && ny < height).then_some((nx, ny))
+    })
+}
+
+/// A two-dimensional grid that stores up to `H` rows of up to `W` elements on the stack.
+///
+/// Cells are indexed by `(x, y)`, where `x` is the column and `y` is the row.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::ReGrid;
+///
+/// const EMPTY: ReGrid<char, 4, 4> = ReGrid::new();
+///
+/// let mut grid = EMPTY;
+/// grid.resize(3, 2, '.');
+/// grid[(2, 1)] = '#';
+///
+/// assert_eq!(grid.width(), 3);
+/// assert_eq!(grid.height(), 2);
+/// assert_eq!(grid.column(2).collect::<Vec<_>>(), vec![&'.', &'#']);
+///
+/// let grid = grid.transpose();
+/// assert_eq!(grid[(1, 2)], '#');
+/// ```
+pub struct ReGrid<T, const W: usize, const H: usize> {
+    rows: ReArr<ReArr<T, W>, H>,
+    width: usize,
+}
+
+impl<T: Clone, const W: usize, const H: usize> Clone for ReGrid<T, W, H> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            width: self.width,
+        }
+    }
+}
+
+impl<T: PartialEq, const W: usize, const H: usize> PartialEq for ReGrid<T, W, H> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.rows == other.rows
+    }
+}
+
+impl<T: Eq, const W: usize, const H: usize> Eq for ReGrid<T, W, H> {}
+
+impl<T, const W: usize, const H: usize> Default for ReGrid<T, W, H> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const W: usize, const H: usize> ReGrid<T, W, H> {
+    /// Create a new, empty [`ReGrid`] with room for `W * H` elements on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let grid = ReGrid::<i32, 3, 2>::new();
+    /// assert!(grid.is_empty());
+    /// assert_eq!(grid.width(), 0);
+    /// assert_eq!(grid.height(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            rows: ReArr::new(),
+            width: 0,
+        }
+    }
+
+    /// How many columns are in the grid.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(3, 1, 0);
+    /// assert_eq!(grid.width(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// How many rows are in the grid.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(3, 1, 0);
+    /// assert_eq!(grid.height(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// How many cells are in the grid.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(3, 2, 0);
+    /// assert_eq!(grid.len(), 6);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.width * self.rows.len()
+    }
+
+    /// Check if there are no cells.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// assert!(grid.is_empty());
+    /// grid.resize(3, 0, 0);
+    /// assert!(grid.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the cell at `(x, y)` as a reference, returning `None` if it is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(2, 2, 7);
+    /// assert_eq!(grid.get(1, 1), Some(&7));
+    /// assert_eq!(grid.get(2, 1), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    /// Get the cell at `(x, y)` as a mutable reference, returning `None` if it is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(2, 2, 7);
+    /// *grid.get_mut(1, 0).unwrap() = 8;
+    /// assert_eq!(grid[(1, 0)], 8);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.rows.get_mut(y)?.get_mut(x)
+    }
+
+    /// Get the row at `y`, returning `None` if it is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(2, 2, 7);
+    /// assert_eq!(grid.row(0).unwrap().len(), 2);
+    /// assert!(grid.row(2).is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn row(&self, y: usize) -> Option<&ReArr<T, W>> {
+        self.rows.get(y)
+    }
+
+    /// Get an iterator over the rows, from top to bottom.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(3, 2, 1);
+    /// assert_eq!(grid.rows().map(|row| row.iter().sum::<i32>()).collect::<Vec<_>>(), vec![3, 3]);
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &ReArr<T, W>> + '_ {
+        self.rows.iter()
+    }
+
+    /// Get an iterator over the cells of the column at `x`, from top to bottom.
+    ///
+    /// The iterator is empty if `x` is out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(3, 2, 1);
+    /// grid[(0, 1)] = 5;
+    /// assert_eq!(grid.column(0).collect::<Vec<_>>(), vec![&1, &5]);
+    /// ```
+    #[inline]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.rows.iter().filter_map(move |row| row.get(x))
+    }
+
+    /// Get an iterator over the columns, from left to right.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(3, 2, 1);
+    /// grid[(2, 0)] = 5;
+    /// assert_eq!(grid.columns().map(|col| col.sum::<i32>()).collect::<Vec<_>>(), vec![2, 2, 6]);
+    /// ```
+    #[inline]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Get an iterator over every cell and its `(x, y)` coordinates, row by row.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(2, 1, 0);
+    /// assert_eq!(grid.iter().collect::<Vec<_>>(), vec![((0, 0), &0), ((1, 0), &0)]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, val)| ((x, y), val)))
+    }
+
+    /// Get an iterator over every cell and its `(x, y)` coordinates, returning mutable references.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<usize, 3, 2>::new();
+    /// grid.resize(3, 2, 0);
+    /// grid.iter_mut().for_each(|((x, y), val)| *val = x + y);
+    /// assert_eq!(grid[(2, 1)], 3);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> + '_ {
+        self.rows.iter_mut().enumerate().flat_map(|(y, row)| {
+            row.iter_mut()
+                .enumerate()
+                .map(move |(x, val)| ((x, y), val))
+        })
+    }
+
+    /// Get an iterator over the up to four cells directly above, left of, right of, and below `(x, y)`,
+    /// along with their coordinates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 3>::new();
+    /// grid.resize(3, 3, 0);
+    /// assert_eq!(grid.neighbors(0, 0).count(), 2);
+    /// assert_eq!(grid.neighbors(1, 1).count(), 4);
+    /// assert_eq!(
+    ///     grid.neighbors(0, 1).map(|(pos, _)| pos).collect::<Vec<_>>(),
+    ///     vec![(0, 0), (1, 1), (0, 2)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        neighbor_coords((x, y), self.width, self.height(), false)
+            .map(|(nx, ny)| ((nx, ny), &self.rows[ny][nx]))
+    }
+
+    /// Get an iterator over the up to eight cells surrounding `(x, y)`, including diagonals,
+    /// along with their coordinates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 3>::new();
+    /// grid.resize(3, 3, 0);
+    /// assert_eq!(grid.neighbors_with_diagonals(0, 0).count(), 3);
+    /// assert_eq!(grid.neighbors_with_diagonals(1, 1).count(), 8);
+    /// ```
+    #[inline]
+    pub fn neighbors_with_diagonals(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        neighbor_coords((x, y), self.width, self.height(), true)
+            .map(|(nx, ny)| ((nx, ny), &self.rows[ny][nx]))
+    }
+
+    /// Resize the grid to `width` columns and `height` rows, filling new cells by calling `f`.
+    ///
+    /// Cells that fall outside of the new size are dropped.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `width` is greater than `W` or `height` is greater than `H`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 3>::new();
+    /// let mut next = 0;
+    /// grid.resize_with(2, 2, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(grid[(1, 1)], 4);
+    /// ```
+    pub fn resize_with<F: FnMut() -> T>(&mut self, width: usize, height: usize, mut f: F) {
+        assert!(
+            width <= W,
+            "new width cannot be greater than the row capacity"
+        );
+        assert!(
+            height <= H,
+            "new height cannot be greater than the column capacity"
+        );
+
+        self.rows.truncate(height);
+        while self.rows.len() < height {
+            self.rows.push(ReArr::new());
+        }
+
+        for row in self.rows.iter_mut() {
+            row.truncate(width);
+            while row.len() < width {
+                row.push(f());
+            }
+        }
+
+        self.width = width;
+    }
+
+    /// Swap the rows and columns of the grid, so the cell at `(x, y)` moves to `(y, x)`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 2>::new();
+    /// grid.resize(3, 2, 0);
+    /// grid[(2, 0)] = 1;
+    ///
+    /// let grid: ReGrid<i32, 2, 3> = grid.transpose();
+    /// assert_eq!(grid.width(), 2);
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid[(0, 2)], 1);
+    /// ```
+    #[must_use]
+    pub fn transpose(self) -> ReGrid<T, H, W> {
+        let width = self.height();
+        let mut rows: ReArr<ReArr<T, H>, W> = ReArr::new();
+        for _ in 0..self.width {
+            rows.push(ReArr::new());
+        }
+
+        for row in self.rows {
+            for (x, val) in row.into_iter().enumerate() {
+                rows[x].push(val);
+            }
+        }
+
+        ReGrid { rows, width }
+    }
+}
+
+impl<T: Clone, const W: usize, const H: usize> ReGrid<T, W, H> {
+    /// Resize the grid to `width` columns and `height` rows, filling new cells with clones of `val`.
+    ///
+    /// Cells that fall outside of the new size are dropped.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `width` is greater than `W` or `height` is greater than `H`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReGrid;
+    ///
+    /// let mut grid = ReGrid::<i32, 3, 3>::new();
+    /// grid.resize(3, 3, 1);
+    /// grid.resize(2, 3, 0);
+    /// assert_eq!(grid.len(), 6);
+    /// assert!(grid.iter().all(|(_, &val)| val == 1));
+    /// ```
+    #[inline]
+    pub fn resize(&mut self, width: usize, height: usize, val: T) {
+        self.resize_with(width, height, || val.clone());
+    }
+}
+
+impl<T, const W: usize, const H: usize> ops::Index<(usize, usize)> for ReGrid<T, W, H> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.rows[y][x]
+    }
+}
+
+impl<T, const W: usize, const H: usize> ops::IndexMut<(usize, usize)> for ReGrid<T, W, H> {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.rows[y][x]
+    }
+}
+
+impl<T: Debug, const W: usize, const H: usize> Debug for ReGrid<T, W, H> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ReGrid")
+            .field("width", &self.width)
+            .field("height", &self.height())
+            .field("rows", &self.rows)
+            .finish()
+    }
+}
@@ -0,0 +1,753 @@
+use crate::{CapacityError, ReArr};
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem, ops,
+};
+
+/// Easy creation of a new [`ReMap`].
+///
+/// ## Panics
+///
+/// Panics if more than `N` distinct keys are given.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_map, ReMap};
+///
+/// let map: ReMap<&str, i32, 4> = re_map! {
+///     "one" => 1,
+///     "two" => 2,
+/// };
+///
+/// assert_eq!(map.get("one"), Some(&1));
+/// assert_eq!(map.len(), 2);
+///
+/// let empty: ReMap<&str, i32, 4> = re_map! {};
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! re_map {
+    () => (
+        $crate::ReMap::new()
+    );
+    ($($key:expr => $val:expr),+ $(,)?) => ({
+        let mut map = $crate::ReMap::new();
+        $(map.insert($key, $val);)+
+        map
+    });
+}
+
+/// A map that stores up to `N` entries on the stack.
+///
+/// The entries are kept in insertion order and found with a linear scan,
+/// so only `K: Eq` is required.
+///
+/// Create a new [`ReMap`] using the [`re_map!`] macro.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_map, ReMap};
+///
+/// const EMPTY: ReMap<&str, i32, 3> = re_map! {};
+///
+/// let mut map = EMPTY;
+/// map.insert("b", 2);
+/// map.insert("a", 1);
+/// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"b", &"a"]);
+///
+/// *map.entry("a").or_insert(0) += 10;
+/// assert_eq!(map["a"], 11);
+/// ```
+pub struct ReMap<K, V, const N: usize> {
+    entries: ReArr<(K, V), N>,
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for ReMap<K, V, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K: Eq, V: PartialEq, const N: usize> PartialEq for ReMap<K, V, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize> Eq for ReMap<K, V, N> {}
+
+impl<K, V, const N: usize> Default for ReMap<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const N: usize> ReMap<K, V, N> {
+    /// Create a new, empty [`ReMap`] with the ability for `N` entries to be stored on the stack.
+    ///
+    /// This is used by the [`re_map!`] macro.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let map = ReMap::<&str, i32, 4>::new();
+    /// assert!(map.is_empty());
+    /// assert_eq!(map.capacity(), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: ReArr::new(),
+        }
+    }
+
+    /// How many entries are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let map: ReMap<i32, i32, 2> = re_map! { 1 => 1, 2 => 4 };
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if there are no entries.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let map = ReMap::<i32, i32, 1>::new();
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// How many entries can be stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let map = ReMap::<i32, i32, 3>::new();
+    /// assert_eq!(map.capacity(), 3);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove all entries.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let mut map: ReMap<i32, i32, 2> = re_map! { 1 => 1, 2 => 4 };
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Retains only the entries specified by the predicate.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let mut map: ReMap<i32, i32, 4> = re_map! { 1 => 1, 2 => 4, 3 => 9 };
+    /// map.retain(|k, v| {
+    ///     *v += 1;
+    ///     k % 2 == 1
+    /// });
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &2), (&3, &10)]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.entries.retain_mut(|(k, v)| f(k, v));
+    }
+
+    /// Get an iterator over the entries, in insertion order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let map: ReMap<i32, i32, 2> = re_map! { 1 => 1, 2 => 4 };
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &1), (&2, &4)]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Get an iterator over the entries, with mutable references to the values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let mut map: ReMap<i32, i32, 2> = re_map! { 1 => 1, 2 => 4 };
+    /// map.iter_mut().for_each(|(_, v)| *v += 1);
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &2), (&2, &5)]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> + '_ {
+        self.entries.iter_mut().map(|(k, v)| (&*k, v))
+    }
+
+    /// Get an iterator over the keys.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let map: ReMap<i32, i32, 2> = re_map! { 1 => 1, 2 => 4 };
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// Get an iterator over the values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let map: ReMap<i32, i32, 2> = re_map! { 1 => 1, 2 => 4 };
+    /// assert_eq!(map.values().collect::<Vec<_>>(), vec![&1, &4]);
+    /// ```
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    /// Get an iterator over mutable references to the values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let mut map: ReMap<i32, i32, 2> = re_map! { 1 => 1, 2 => 4 };
+    /// map.values_mut().for_each(|v| *v *= 2);
+    /// assert_eq!(map.values().collect::<Vec<_>>(), vec![&2, &8]);
+    /// ```
+    #[inline]
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.entries.iter_mut().map(|(_, v)| v)
+    }
+}
+
+impl<K: Eq, V, const N: usize> ReMap<K, V, N> {
+    fn find<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        self.entries.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    /// Insert a key-value pair into the map.
+    ///
+    /// If the map already had the key, the value is updated and the old value is returned.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the key is new and the map is full.
+    /// See [`ReMap::try_insert`] for a non-panicking version.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut map = ReMap::<i32, &str, 2>::new();
+    /// assert_eq!(map.insert(1, "a"), None);
+    /// assert_eq!(map.insert(1, "b"), Some("a"));
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, key: K, val: V) -> Option<V> {
+        match self.try_insert(key, val) {
+            Ok(old) => old,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Insert a key-value pair into the map.
+    ///
+    /// If the map already had the key, the value is updated and the old value is returned.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the key is new and the map is full.
+    /// The key-value pair can be recovered with [`CapacityError::into_inner`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut map = ReMap::<i32, &str, 1>::new();
+    /// assert_eq!(map.try_insert(1, "a"), Ok(None));
+    /// assert_eq!(map.try_insert(1, "b"), Ok(Some("a")));
+    /// assert_eq!(map.try_insert(2, "c").unwrap_err().into_inner(), (2, "c"));
+    /// ```
+    pub fn try_insert(&mut self, key: K, val: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        if let Some(idx) = self.find(&key) {
+            return Ok(Some(mem::replace(&mut self.entries[idx].1, val)));
+        }
+
+        if self.entries.len() == N {
+            return Err(CapacityError::new(N + 1, N).with_inner((key, val)));
+        }
+
+        self.entries.push((key, val));
+        Ok(None)
+    }
+
+    /// Get a reference to the value of the key, returning `None` if the key isn't in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let map: ReMap<i32, &str, 2> = re_map! { 1 => "a" };
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get<Q: ?Sized + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).map(|idx| &self.entries[idx].1)
+    }
+
+    /// Get a mutable reference to the value of the key, returning `None` if the key isn't in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let mut map: ReMap<i32, &str, 2> = re_map! { 1 => "a" };
+    /// if let Some(x) = map.get_mut(&1) {
+    ///     *x = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).map(|idx| &mut self.entries[idx].1)
+    }
+
+    /// Check if the map contains the key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let map: ReMap<i32, &str, 2> = re_map! { 1 => "a" };
+    /// assert!(map.contains_key(&1));
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Remove the key from the map, returning its value if the key was in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let mut map: ReMap<i32, &str, 2> = re_map! { 1 => "a" };
+    /// assert_eq!(map.remove(&1), Some("a"));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    #[inline]
+    pub fn remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Remove the key from the map, returning the stored key and its value if the key was in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let mut map: ReMap<i32, &str, 2> = re_map! { 1 => "a" };
+    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
+    /// assert_eq!(map.remove_entry(&1), None);
+    /// ```
+    #[inline]
+    pub fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).map(|idx| self.entries.remove(idx))
+    }
+
+    /// Get the entry of the key for in-place manipulation.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut letters = ReMap::<char, u32, 16>::new();
+    /// for ch in "a short treatise on fungi".chars() {
+    ///     *letters.entry(ch).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(letters[&'s'], 2);
+    /// assert_eq!(letters[&'t'], 3);
+    /// assert_eq!(letters.get(&'y'), None);
+    /// ```
+    #[inline]
+    pub fn entry(&mut self, key: K) -> ReMapEntry<'_, K, V, N> {
+        match self.find(&key) {
+            Some(idx) => ReMapEntry::Occupied(ReMapOccupiedEntry { map: self, idx }),
+            None => ReMapEntry::Vacant(ReMapVacantEntry { map: self, key }),
+        }
+    }
+
+    /// Insert all the key-value pairs from the given iterator.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the iterator tries to add more keys than the map can hold.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_map, ReMap};
+    ///
+    /// let mut map: ReMap<i32, &str, 2> = re_map! { 1 => "a" };
+    /// map.extend([(2, "b"), (1, "c")]);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map[&1], "c");
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
+/// A view into a single entry of a [`ReMap`], which may either be vacant or occupied.
+///
+/// Created by [`ReMap::entry`].
+pub enum ReMapEntry<'a, K, V, const N: usize> {
+    /// The key is in the map.
+    Occupied(ReMapOccupiedEntry<'a, K, V, N>),
+    /// The key isn't in the map.
+    Vacant(ReMapVacantEntry<'a, K, V, N>),
+}
+
+/// A view into an occupied entry of a [`ReMap`].
+pub struct ReMapOccupiedEntry<'a, K, V, const N: usize> {
+    map: &'a mut ReMap<K, V, N>,
+    idx: usize,
+}
+
+/// A view into a vacant entry of a [`ReMap`].
+pub struct ReMapVacantEntry<'a, K, V, const N: usize> {
+    map: &'a mut ReMap<K, V, N>,
+    key: K,
+}
+
+impl<'a, K: Eq, V, const N: usize> ReMapEntry<'a, K, V, N> {
+    /// Get the key of this entry.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut map = ReMap::<&str, i32, 2>::new();
+    /// assert_eq!(map.entry("a").key(), &"a");
+    /// ```
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Insert `default` if the entry is vacant, and return a mutable reference to the value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut map = ReMap::<&str, i32, 2>::new();
+    /// *map.entry("a").or_insert(1) += 1;
+    /// *map.entry("a").or_insert(1) += 1;
+    /// assert_eq!(map["a"], 3);
+    /// ```
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of `f` if the entry is vacant, and return a mutable reference to the value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut map = ReMap::<&str, String, 2>::new();
+    /// map.entry("a").or_insert_with(|| "hello".to_string()).push('!');
+    /// assert_eq!(map["a"], "hello!");
+    /// ```
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, f: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// Insert the result of calling `f` with the key if the entry is vacant,
+    /// and return a mutable reference to the value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut map = ReMap::<&str, usize, 2>::new();
+    /// map.entry("hello").or_insert_with_key(|key| key.len());
+    /// assert_eq!(map["hello"], 5);
+    /// ```
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, f: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => {
+                let val = f(entry.key());
+                entry.insert(val)
+            }
+        }
+    }
+
+    /// Modify the value in place if the entry is occupied.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut map = ReMap::<&str, i32, 2>::new();
+    /// map.entry("a").and_modify(|v| *v += 1).or_insert(1);
+    /// map.entry("a").and_modify(|v| *v += 1).or_insert(1);
+    /// assert_eq!(map["a"], 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, K: Eq, V: Default, const N: usize> ReMapEntry<'a, K, V, N> {
+    /// Insert the default value if the entry is vacant, and return a mutable reference to the value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReMap;
+    ///
+    /// let mut map = ReMap::<&str, i32, 2>::new();
+    /// *map.entry("a").or_default() += 1;
+    /// assert_eq!(map["a"], 1);
+    /// ```
+    #[inline]
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K: Eq, V, const N: usize> ReMapOccupiedEntry<'a, K, V, N> {
+    /// Get the key of this entry.
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.map.entries[self.idx].0
+    }
+
+    /// Get a reference to the value of this entry.
+    #[must_use]
+    #[inline]
+    pub fn get(&self) -> &V {
+        &self.map.entries[self.idx].1
+    }
+
+    /// Get a mutable reference to the value of this entry.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.entries[self.idx].1
+    }
+
+    /// Convert this entry into a mutable reference to its value, with the lifetime of the map.
+    #[must_use]
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.entries[self.idx].1
+    }
+
+    /// Replace the value of this entry, returning the old value.
+    #[inline]
+    pub fn insert(&mut self, val: V) -> V {
+        mem::replace(self.get_mut(), val)
+    }
+
+    /// Remove this entry from the map, returning its value.
+    #[must_use]
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Remove this entry from the map, returning the stored key and its value.
+    #[must_use]
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.entries.remove(self.idx)
+    }
+}
+
+impl<'a, K: Eq, V, const N: usize> ReMapVacantEntry<'a, K, V, N> {
+    /// Get the key of this entry.
+    #[inline]
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take ownership of the key.
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Insert the value into the map, returning a mutable reference to it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the map is full.
+    #[inline]
+    pub fn insert(self, val: V) -> &'a mut V {
+        let idx = self.map.len();
+        self.map.entries.push((self.key, val));
+        &mut self.map.entries[idx].1
+    }
+}
+
+impl<K: Eq + Borrow<Q>, V, Q: ?Sized + Eq, const N: usize> ops::Index<&Q> for ReMap<K, V, N> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for ReMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = <ReArr<(K, V), N> as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<K: Eq, V, const N: usize> FromIterator<(K, V)> for ReMap<K, V, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Debug, V: Debug, const N: usize> Debug for ReMap<K, V, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
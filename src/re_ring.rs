@@ -0,0 +1,856 @@
+use crate::Slot;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    array::IntoIter as ArrayIter,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter::{Flatten, Take},
+    marker::PhantomData,
+    mem, ops,
+};
+
+/// Easy creation of a new [`ReRing`].
+///
+/// The elements are listed from oldest to newest.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_ring, ReRing};
+///
+/// const SOME_ITEMS: ReRing<i8, 3> = re_ring![1, 2, 3];
+/// const MANY_ITEMS: ReRing<u16, 90> = re_ring![5; 90];
+/// const EXTRA_ITEMS: ReRing<&str, 5> = re_ring!["Hello", "world", "!"; None, None];
+///
+/// // Infer the type and size of the ReRing
+/// const NO_STACK_F32: ReRing<f32, 0> = re_ring![];
+///
+/// let mut history = EXTRA_ITEMS;
+/// history.push("again");
+/// assert_eq!(history.oldest(), Some(&"Hello"));
+/// assert_eq!(history.latest(), Some(&"again"));
+/// ```
+#[macro_export]
+macro_rules! re_ring {
+    () => (
+        $crate::ReRing::new()
+    );
+    ($elem:expr; $n:expr) => (
+        $crate::ReRing::from_arr([Some($elem); $n])
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::ReRing::from_arr([$(Some($x)),+])
+    );
+    ($($x:expr),+; $($rest:expr),* $(,)?) => (
+        $crate::ReRing::from_arr([$(Some($x)),+, $($rest),*])
+    );
+}
+
+/// A [`ReRing`] is a fixed-size ring buffer that keeps the newest `N` elements.
+///
+/// Pushing onto a full [`ReRing`] overwrites the oldest element and hands it back.
+/// Iteration always goes from the oldest element to the newest.
+///
+/// Create a new [`ReRing`] using the [`re_ring!`] macro.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_ring, ReRing};
+///
+/// let mut samples: ReRing<u32, 3> = re_ring![];
+/// assert_eq!(samples.push(10), None);
+/// assert_eq!(samples.push(20), None);
+/// assert_eq!(samples.push(30), None);
+///
+/// // The buffer is full, so the oldest sample gets evicted
+/// assert_eq!(samples.push(40), Some(10));
+/// assert!(samples.iter().eq(&[20, 30, 40]));
+/// assert_eq!(samples.oldest(), Some(&20));
+/// assert_eq!(samples.latest(), Some(&40));
+/// ```
+///
+/// ## Storage
+///
+/// Like a [`ReArr`](crate::ReArr), each element is kept in a [`Slot`] `S`, which defaults to [`Option<T>`].
+/// For `T: Default`, `ReRing<T, N, T>` stores the elements directly,
+/// which also lets it hand them out as plain slices:
+///
+/// ```rust
+/// use combo_vec::ReRing;
+///
+/// // `new` is only for `Option<T>` slots, so create other rings with `Default`
+/// let mut dense = ReRing::<u32, 3, u32>::default();
+/// dense.extend([1, 2, 3, 4]);
+/// assert_eq!(dense.as_slices(), (&[2, 3][..], &[4][..]));
+/// assert_eq!(dense.make_contiguous(), &[2, 3, 4]);
+/// ```
+pub struct ReRing<T, const N: usize, S = Option<T>> {
+    arr: [S; N],
+    // The index of the oldest element
+    head: usize,
+    len: usize,
+    elem: PhantomData<T>,
+}
+
+impl<T, S: Clone, const N: usize> Clone for ReRing<T, N, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            arr: self.arr.clone(),
+            head: self.head,
+            len: self.len,
+            elem: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, S: Slot<T>, const N: usize> PartialEq for ReRing<T, N, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq + Eq, S: Slot<T>, const N: usize> Eq for ReRing<T, N, S> {}
+
+impl<T: Hash, S: Slot<T>, const N: usize> Hash for ReRing<T, N, S> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter().for_each(|x| x.hash(state));
+    }
+}
+
+impl<T, S: Slot<T>, const N: usize> Default for ReRing<T, N, S> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            arr: core::array::from_fn(|_| S::empty()),
+            head: 0,
+            len: 0,
+            elem: PhantomData,
+        }
+    }
+}
+
+// Slot trait methods can't be called in a `const fn`, so everything that touches a slot
+// and is `const` for `Option<T>` slots is implemented once per slot type.
+impl<T, const N: usize> ReRing<T, N> {
+    const DEFAULT_ARR_VALUE: Option<T> = None;
+
+    /// Create a new, empty [`ReRing`] with room for `N` elements.
+    ///
+    /// This is used by the [`re_ring!`] macro, and you should consider using it instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// const RE_RING: ReRing::<i32, 3> = re_ring![];
+    /// let my_re_ring = ReRing::<i32, 3>::new();
+    /// assert_eq!(my_re_ring, RE_RING);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            arr: [Self::DEFAULT_ARR_VALUE; N],
+            head: 0,
+            len: 0,
+            elem: PhantomData,
+        }
+    }
+
+    /// Create a new [`ReRing`] from an array, ordered from oldest to newest.
+    ///
+    /// All slots must be populated with `Some` values until
+    /// the first `None` value is encountered, or the end of the array is reached.
+    /// After that, all remaining slots must be `None`.
+    ///
+    /// This is used by the [`re_ring!`] macro.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let my_re_ring = ReRing::from_arr([Some(1), Some(2), Some(3), None, None]);
+    /// let convenient_re_ring = re_ring![1, 2, 3; None, None];
+    ///
+    /// assert_eq!(my_re_ring, convenient_re_ring);
+    /// assert_eq!(my_re_ring.len(), 3);
+    /// assert_eq!(my_re_ring.capacity(), 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_arr(arr: [Option<T>; N]) -> Self {
+        let mut len = 0;
+        while len < N && arr[len].is_some() {
+            len += 1;
+        }
+
+        Self {
+            arr,
+            head: 0,
+            len,
+            elem: PhantomData,
+        }
+    }
+
+    /// Remove the oldest element and return it, or None if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2, 3];
+    /// my_re_ring.push(4);
+    ///
+    /// assert_eq!(my_re_ring.pop_oldest(), Some(2));
+    /// assert_eq!(my_re_ring.pop_oldest(), Some(3));
+    /// assert_eq!(my_re_ring.pop_oldest(), Some(4));
+    /// assert_eq!(my_re_ring.pop_oldest(), None);
+    /// ```
+    #[inline]
+    pub const fn pop_oldest(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let oldest = self.arr[self.head].take();
+        self.head = self.slot(1);
+        self.len -= 1;
+        oldest
+    }
+
+    /// Remove the newest element and return it, or None if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2, 3];
+    /// my_re_ring.push(4);
+    ///
+    /// assert_eq!(my_re_ring.pop_latest(), Some(4));
+    /// assert_eq!(my_re_ring.pop_latest(), Some(3));
+    /// assert_eq!(my_re_ring.pop_latest(), Some(2));
+    /// assert_eq!(my_re_ring.pop_latest(), None);
+    /// ```
+    #[inline]
+    pub const fn pop_latest(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        self.arr[slot].take()
+    }
+
+    /// Get the element `idx` places after the oldest one, returning `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2, 3];
+    /// my_re_ring.push(4);
+    ///
+    /// assert_eq!(my_re_ring.get(0), Some(&2));
+    /// assert_eq!(my_re_ring.get(2), Some(&4));
+    /// assert_eq!(my_re_ring.get(3), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn get(&self, idx: usize) -> Option<&T> {
+        if idx < self.len {
+            self.arr[self.slot(idx)].as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Get the element `idx` places after the oldest one as a mutable reference, `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2, 3];
+    /// my_re_ring.push(4);
+    ///
+    /// if let Some(x) = my_re_ring.get_mut(2) {
+    ///     *x = 5;
+    /// }
+    ///
+    /// assert!(my_re_ring.iter().eq(&[2, 3, 5]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        if idx < self.len {
+            self.arr[self.slot(idx)].as_mut()
+        } else {
+            None
+        }
+    }
+
+    /// Get the newest element, returning `None` if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring: ReRing<i32, 2> = re_ring![];
+    /// assert_eq!(my_re_ring.latest(), None);
+    ///
+    /// my_re_ring.push(1);
+    /// my_re_ring.push(2);
+    /// my_re_ring.push(3);
+    /// assert_eq!(my_re_ring.latest(), Some(&3));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn latest(&self) -> Option<&T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.get(self.len - 1)
+        }
+    }
+
+    /// Get the oldest element, returning `None` if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring: ReRing<i32, 2> = re_ring![];
+    /// assert_eq!(my_re_ring.oldest(), None);
+    ///
+    /// my_re_ring.push(1);
+    /// my_re_ring.push(2);
+    /// my_re_ring.push(3);
+    /// assert_eq!(my_re_ring.oldest(), Some(&2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn oldest(&self) -> Option<&T> {
+        self.get(0)
+    }
+}
+
+impl<E: Default, const N: usize> ReRing<E, N, E> {
+    /// Remove the oldest element and return it, or None if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReRing;
+    ///
+    /// let mut my_re_ring = [1, 2, 3, 4].into_iter().collect::<ReRing<i32, 3, i32>>();
+    ///
+    /// assert_eq!(my_re_ring.pop_oldest(), Some(2));
+    /// assert_eq!(my_re_ring.pop_oldest(), Some(3));
+    /// assert_eq!(my_re_ring.pop_oldest(), Some(4));
+    /// assert_eq!(my_re_ring.pop_oldest(), None);
+    /// ```
+    #[inline]
+    pub fn pop_oldest(&mut self) -> Option<E> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let oldest = mem::take(&mut self.arr[self.head]);
+        self.head = self.slot(1);
+        self.len -= 1;
+        Some(oldest)
+    }
+
+    /// Remove the newest element and return it, or None if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReRing;
+    ///
+    /// let mut my_re_ring = [1, 2, 3, 4].into_iter().collect::<ReRing<i32, 3, i32>>();
+    ///
+    /// assert_eq!(my_re_ring.pop_latest(), Some(4));
+    /// assert_eq!(my_re_ring.pop_latest(), Some(3));
+    /// assert_eq!(my_re_ring.pop_latest(), Some(2));
+    /// assert_eq!(my_re_ring.pop_latest(), None);
+    /// ```
+    #[inline]
+    pub fn pop_latest(&mut self) -> Option<E> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        Some(mem::take(&mut self.arr[slot]))
+    }
+
+    /// Get the element `idx` places after the oldest one, returning `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReRing;
+    ///
+    /// let my_re_ring = [1, 2, 3, 4].into_iter().collect::<ReRing<i32, 3, i32>>();
+    ///
+    /// assert_eq!(my_re_ring.get(0), Some(&2));
+    /// assert_eq!(my_re_ring.get(2), Some(&4));
+    /// assert_eq!(my_re_ring.get(3), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn get(&self, idx: usize) -> Option<&E> {
+        if idx < self.len {
+            Some(&self.arr[self.slot(idx)])
+        } else {
+            None
+        }
+    }
+
+    /// Get the element `idx` places after the oldest one as a mutable reference, `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReRing;
+    ///
+    /// let mut my_re_ring = [1, 2, 3, 4].into_iter().collect::<ReRing<i32, 3, i32>>();
+    ///
+    /// if let Some(x) = my_re_ring.get_mut(2) {
+    ///     *x = 5;
+    /// }
+    ///
+    /// assert!(my_re_ring.iter().eq(&[2, 3, 5]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn get_mut(&mut self, idx: usize) -> Option<&mut E> {
+        if idx < self.len {
+            Some(&mut self.arr[self.slot(idx)])
+        } else {
+            None
+        }
+    }
+
+    /// Get the newest element, returning `None` if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReRing;
+    ///
+    /// let my_re_ring = [1, 2, 3].into_iter().collect::<ReRing<i32, 2, i32>>();
+    /// assert_eq!(my_re_ring.latest(), Some(&3));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn latest(&self) -> Option<&E> {
+        if self.len == 0 {
+            None
+        } else {
+            self.get(self.len - 1)
+        }
+    }
+
+    /// Get the oldest element, returning `None` if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReRing;
+    ///
+    /// let my_re_ring = [1, 2, 3].into_iter().collect::<ReRing<i32, 2, i32>>();
+    /// assert_eq!(my_re_ring.oldest(), Some(&2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn oldest(&self) -> Option<&E> {
+        self.get(0)
+    }
+
+    /// Get the elements as two slices, which together hold every element from oldest to newest.
+    ///
+    /// The second slice is only non-empty when the elements wrap around the end of the underlying array.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReRing;
+    ///
+    /// let mut my_re_ring = [1, 2, 3].into_iter().collect::<ReRing<i32, 3, i32>>();
+    /// assert_eq!(my_re_ring.as_slices(), (&[1, 2, 3][..], &[][..]));
+    ///
+    /// my_re_ring.push(4);
+    /// assert_eq!(my_re_ring.as_slices(), (&[2, 3][..], &[4][..]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_slices(&self) -> (&[E], &[E]) {
+        self.slots()
+    }
+
+    /// Reorder the underlying array so the oldest element is in the first slot,
+    /// and return all the elements as one slice.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReRing;
+    ///
+    /// let mut my_re_ring = [1, 2, 3, 4].into_iter().collect::<ReRing<i32, 3, i32>>();
+    /// my_re_ring.make_contiguous().sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(my_re_ring.as_slices(), (&[4, 3, 2][..], &[][..]));
+    /// ```
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut [E] {
+        self.arr.rotate_left(self.head);
+        self.head = 0;
+        &mut self.arr[..self.len]
+    }
+}
+
+impl<T, S: Slot<T>, const N: usize> ReRing<T, N, S> {
+    // Get the index in `arr` of the element `idx` places after the oldest one.
+    #[inline]
+    const fn slot(&self, idx: usize) -> usize {
+        let slot = self.head + idx;
+        if slot >= N {
+            slot - N
+        } else {
+            slot
+        }
+    }
+
+    // Get the slots holding the elements from oldest to newest, split where they wrap around the end of the array.
+    #[inline]
+    fn slots(&self) -> (&[S], &[S]) {
+        let end = self.head + self.len;
+        if end > N {
+            (&self.arr[self.head..], &self.arr[..end - N])
+        } else {
+            (&self.arr[self.head..end], &[])
+        }
+    }
+
+    #[inline]
+    fn slots_mut(&mut self) -> (&mut [S], &mut [S]) {
+        let end = self.head + self.len;
+        let (newer, older) = self.arr.split_at_mut(self.head);
+        if end > N {
+            (older, &mut newer[..end - N])
+        } else {
+            (&mut older[..self.len], &mut [])
+        }
+    }
+
+    /// Push an element as the newest one.
+    ///
+    /// If the ring is full, the oldest element is overwritten and returned.
+    /// A [`ReRing`] with no capacity hands the element straight back.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2; None];
+    /// assert_eq!(my_re_ring.push(3), None);
+    /// assert_eq!(my_re_ring.push(4), Some(1));
+    /// assert!(my_re_ring.iter().eq(&[2, 3, 4]));
+    /// ```
+    #[inline]
+    pub fn push(&mut self, val: T) -> Option<T> {
+        if N == 0 {
+            return Some(val);
+        }
+
+        if self.len < N {
+            let slot = self.slot(self.len);
+            self.arr[slot] = S::fill(val);
+            self.len += 1;
+            None
+        } else {
+            let evicted = mem::replace(&mut self.arr[self.head], S::fill(val)).take();
+            self.head = self.slot(1);
+            Some(evicted)
+        }
+    }
+
+    // Put an element back in front of the oldest one, for containers that spill their oldest elements elsewhere.
+    #[inline]
+    pub(crate) fn push_oldest(&mut self, val: T) {
+        assert!(self.len < N, "cannot push onto a full ring");
+
+        self.head = self.slot(N - 1);
+        self.arr[self.head] = S::fill(val);
+        self.len += 1;
+    }
+
+    /// How many elements are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2; None];
+    /// assert_eq!(my_re_ring.len(), 2);
+    /// my_re_ring.push(3);
+    /// my_re_ring.push(4);
+    /// assert_eq!(my_re_ring.len(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// How many elements can be stored before the oldest ones start getting overwritten.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let my_re_ring = re_ring![1, 2; None];
+    /// assert_eq!(my_re_ring.capacity(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Check if the ring is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring: ReRing<i32, 2> = re_ring![];
+    /// assert!(my_re_ring.is_empty());
+    /// my_re_ring.push(1);
+    /// assert!(!my_re_ring.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if the ring is full, so the next push will overwrite the oldest element.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2; None];
+    /// assert!(!my_re_ring.is_full());
+    /// my_re_ring.push(3);
+    /// assert!(my_re_ring.is_full());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Remove all elements from the ring.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2, 3];
+    /// my_re_ring.clear();
+    /// assert!(my_re_ring.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.arr.iter_mut().for_each(|x| *x = S::empty());
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Get an iterator over the elements of the ring, from oldest to newest.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2, 3];
+    /// my_re_ring.push(4);
+    /// let mut iter = my_re_ring.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&4));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let (older, newer) = self.slots();
+        older.iter().chain(newer).map(S::get)
+    }
+
+    /// Get an iterator over the elements of the ring from oldest to newest, returning mutable references.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2, 3];
+    /// my_re_ring.push(4);
+    /// for i in my_re_ring.iter_mut() {
+    ///    *i += 1;
+    /// }
+    /// assert!(my_re_ring.iter().eq(&[3, 4, 5]));
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> + '_ {
+        let (older, newer) = self.slots_mut();
+        older.iter_mut().chain(newer).map(S::get_mut)
+    }
+
+    /// Push every element of the given iterator, keeping only the newest `N` elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring: ReRing<i32, 3> = re_ring![];
+    /// my_re_ring.extend(1..=5);
+    /// assert!(my_re_ring.iter().eq(&[3, 4, 5]));
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| {
+            self.push(val);
+        });
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, S: Slot<T>, const N: usize> ReRing<T, N, S> {
+    /// Clone all the elements into a [`Vec`], from oldest to newest.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_ring, ReRing};
+    ///
+    /// let mut my_re_ring = re_ring![1, 2, 3];
+    /// my_re_ring.push(4);
+    /// assert_eq!(my_re_ring.to_vec(), vec![2, 3, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, S: Slot<T>, const N: usize> ops::Index<usize> for ReRing<T, N, S> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &Self::Output {
+        assert!(idx < self.len, "index out of bounds");
+        self.arr[self.slot(idx)].get()
+    }
+}
+
+impl<T, S: Slot<T>, const N: usize> ops::IndexMut<usize> for ReRing<T, N, S> {
+    #[inline]
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        assert!(idx < self.len, "index out of bounds");
+        self.arr[self.slot(idx)].get_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ReRing<T, N> {
+    type Item = T;
+    type IntoIter = Flatten<ArrayIter<Option<T>, N>>;
+
+    #[inline]
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.arr.rotate_left(self.head);
+        self.arr.into_iter().flatten()
+    }
+}
+
+impl<E: Default, const N: usize> IntoIterator for ReRing<E, N, E> {
+    type Item = E;
+    type IntoIter = Take<ArrayIter<E, N>>;
+
+    #[inline]
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.arr.rotate_left(self.head);
+        self.arr.into_iter().take(self.len)
+    }
+}
+
+impl<E: Default, const N: usize> From<ReRing<E, N>> for ReRing<E, N, E> {
+    #[inline]
+    fn from(ring: ReRing<E, N>) -> Self {
+        ring.into_iter().collect()
+    }
+}
+
+impl<T: Default, const N: usize> From<ReRing<T, N, T>> for ReRing<T, N> {
+    #[inline]
+    fn from(ring: ReRing<T, N, T>) -> Self {
+        ring.into_iter().collect()
+    }
+}
+
+impl<T, S: Slot<T>, const N: usize> FromIterator<T> for ReRing<T, N, S> {
+    /// Collect the newest `N` elements of the iterator.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut re_ring = Self::default();
+        re_ring.extend(iter);
+        re_ring
+    }
+}
+
+impl<T, S: Debug, const N: usize> Debug for ReRing<T, N, S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ReRing")
+            .field("arr", &self.arr)
+            .field("head", &self.head)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T: Debug, S: Slot<T>, const N: usize> Display for ReRing<T, N, S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
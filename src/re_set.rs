@@ -0,0 +1,495 @@
+use crate::{CapacityError, ReMap, ReMapEntry};
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    iter::Map,
+};
+
+/// Easy creation of a new [`ReSet`].
+///
+/// ## Panics
+///
+/// Panics if more than `N` distinct values are given.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_set, ReSet};
+///
+/// let set: ReSet<i32, 4> = re_set![1, 2, 3, 2];
+/// assert_eq!(set.len(), 3);
+/// assert!(set.contains(&2));
+///
+/// let empty: ReSet<i32, 4> = re_set![];
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! re_set {
+    () => (
+        $crate::ReSet::new()
+    );
+    ($($x:expr),+ $(,)?) => ({
+        let mut set = $crate::ReSet::new();
+        $(set.insert($x);)+
+        set
+    });
+}
+
+/// A set that stores up to `N` values on the stack.
+///
+/// The values are kept in insertion order and found with a linear scan,
+/// so only `T: Eq` is required.
+///
+/// Unlike [`ReArr`](crate::ReArr), two sets are equal if they hold the same values in any order.
+///
+/// Create a new [`ReSet`] using the [`re_set!`] macro.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{re_set, ReSet};
+///
+/// const EMPTY: ReSet<i32, 3> = re_set![];
+///
+/// let mut set = EMPTY;
+/// assert!(set.insert(2));
+/// assert!(set.insert(1));
+/// assert!(!set.insert(2));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&2, &1]);
+///
+/// let other: ReSet<i32, 3> = re_set![1, 2];
+/// assert_eq!(set, other);
+/// ```
+pub struct ReSet<T, const N: usize> {
+    map: ReMap<T, (), N>,
+}
+
+impl<T: Clone, const N: usize> Clone for ReSet<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: Eq, const N: usize> PartialEq for ReSet<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ReSet<T, N> {}
+
+impl<T, const N: usize> Default for ReSet<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ReSet<T, N> {
+    /// Create a new, empty [`ReSet`] with the ability for `N` values to be stored on the stack.
+    ///
+    /// This is used by the [`re_set!`] macro.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSet;
+    ///
+    /// let set = ReSet::<i32, 4>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { map: ReMap::new() }
+    }
+
+    /// How many values are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let set: ReSet<i32, 2> = re_set![1, 2];
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check if there are no values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSet;
+    ///
+    /// let set = ReSet::<i32, 1>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// How many values can be stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSet;
+    ///
+    /// let set = ReSet::<i32, 3>::new();
+    /// assert_eq!(set.capacity(), 3);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove all values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let mut set: ReSet<i32, 2> = re_set![1, 2];
+    /// set.clear();
+    /// assert!(set.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Retains only the values specified by the predicate.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let mut set: ReSet<i32, 4> = re_set![1, 2, 3, 4];
+    /// set.retain(|x| x % 2 == 0);
+    /// assert_eq!(set, re_set![2, 4]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.map.retain(|x, ()| f(x));
+    }
+
+    /// Get an iterator over the values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let set: ReSet<i32, 4> = re_set![3, 1, 2];
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&3, &1, &2]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.map.keys()
+    }
+}
+
+impl<T: Eq, const N: usize> ReSet<T, N> {
+    /// Add a value to the set, returning whether it was newly added.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the value is new and the set is full.
+    /// See [`ReSet::try_insert`] for a non-panicking version.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSet;
+    ///
+    /// let mut set = ReSet::<i32, 2>::new();
+    /// assert!(set.insert(1));
+    /// assert!(!set.insert(1));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, val: T) -> bool {
+        match self.try_insert(val) {
+            Ok(added) => added,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Add a value to the set, returning whether it was newly added.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the value is new and the set is full.
+    /// The value can be recovered with [`CapacityError::into_inner`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSet;
+    ///
+    /// let mut set = ReSet::<i32, 1>::new();
+    /// assert_eq!(set.try_insert(1), Ok(true));
+    /// assert_eq!(set.try_insert(1), Ok(false));
+    /// assert_eq!(set.try_insert(2).unwrap_err().into_inner(), 2);
+    /// ```
+    pub fn try_insert(&mut self, val: T) -> Result<bool, CapacityError<T>> {
+        let full = self.len() == N;
+
+        match self.map.entry(val) {
+            ReMapEntry::Occupied(_) => Ok(false),
+            ReMapEntry::Vacant(entry) if full => {
+                Err(CapacityError::new(N + 1, N).with_inner(entry.into_key()))
+            }
+            ReMapEntry::Vacant(entry) => {
+                entry.insert(());
+                Ok(true)
+            }
+        }
+    }
+
+    /// Check if the set contains the value.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let set: ReSet<&str, 3> = re_set!["a", "b", "c"];
+    /// assert!(set.contains("a"));
+    /// assert!(!set.contains("d"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains<Q: ?Sized + Eq>(&self, val: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.contains_key(val)
+    }
+
+    /// Remove a value from the set, returning whether it was present.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let mut set: ReSet<i32, 2> = re_set![1, 2];
+    /// assert!(set.remove(&1));
+    /// assert!(!set.remove(&1));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[inline]
+    pub fn remove<Q: ?Sized + Eq>(&mut self, val: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove(val).is_some()
+    }
+
+    /// Remove a value from the set and return it, or `None` if it wasn't present.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let mut set: ReSet<String, 2> = re_set![String::from("a")];
+    /// assert_eq!(set.take("a"), Some(String::from("a")));
+    /// assert_eq!(set.take("a"), None);
+    /// ```
+    #[inline]
+    pub fn take<Q: ?Sized + Eq>(&mut self, val: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove_entry(val).map(|(x, ())| x)
+    }
+
+    /// Add all values of an iterator to the set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSet;
+    ///
+    /// let mut set = ReSet::<i32, 3>::new();
+    /// set.extend([1, 2, 1, 3]);
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| {
+            self.insert(x);
+        });
+    }
+
+    /// Get an iterator over the values that are in `self` or `other`, without duplicates.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let a: ReSet<i32, 4> = re_set![1, 2, 3];
+    /// let b: ReSet<i32, 3> = re_set![2, 3, 4];
+    /// assert_eq!(a.union(&b).collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// ```
+    #[inline]
+    pub fn union<'a, const M: usize>(
+        &'a self,
+        other: &'a ReSet<T, M>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().chain(other.difference(self))
+    }
+
+    /// Get an iterator over the values that are in both `self` and `other`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let a: ReSet<i32, 4> = re_set![1, 2, 3];
+    /// let b: ReSet<i32, 3> = re_set![2, 3, 4];
+    /// assert_eq!(a.intersection(&b).collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    #[inline]
+    pub fn intersection<'a, const M: usize>(
+        &'a self,
+        other: &'a ReSet<T, M>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().filter(|x| other.contains(*x))
+    }
+
+    /// Get an iterator over the values that are in `self` but not in `other`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let a: ReSet<i32, 4> = re_set![1, 2, 3];
+    /// let b: ReSet<i32, 3> = re_set![2, 3, 4];
+    /// assert_eq!(a.difference(&b).collect::<Vec<_>>(), vec![&1]);
+    /// ```
+    #[inline]
+    pub fn difference<'a, const M: usize>(
+        &'a self,
+        other: &'a ReSet<T, M>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().filter(|x| !other.contains(*x))
+    }
+
+    /// Get an iterator over the values that are in `self` or `other`, but not in both.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let a: ReSet<i32, 4> = re_set![1, 2, 3];
+    /// let b: ReSet<i32, 3> = re_set![2, 3, 4];
+    /// assert_eq!(a.symmetric_difference(&b).collect::<Vec<_>>(), vec![&1, &4]);
+    /// ```
+    #[inline]
+    pub fn symmetric_difference<'a, const M: usize>(
+        &'a self,
+        other: &'a ReSet<T, M>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.difference(other).chain(other.difference(self))
+    }
+
+    /// Check if every value in `self` is also in `other`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let a: ReSet<i32, 4> = re_set![1, 2];
+    /// let b: ReSet<i32, 3> = re_set![1, 2, 3];
+    /// assert!(a.is_subset(&b));
+    /// assert!(!b.is_subset(&a));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_subset<const M: usize>(&self, other: &ReSet<T, M>) -> bool {
+        self.len() <= other.len() && self.iter().all(|x| other.contains(x))
+    }
+
+    /// Check if every value in `other` is also in `self`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let a: ReSet<i32, 4> = re_set![1, 2, 3];
+    /// let b: ReSet<i32, 2> = re_set![1, 2];
+    /// assert!(a.is_superset(&b));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_superset<const M: usize>(&self, other: &ReSet<T, M>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Check if `self` and `other` have no values in common.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{re_set, ReSet};
+    ///
+    /// let a: ReSet<i32, 4> = re_set![1, 2];
+    /// let b: ReSet<i32, 2> = re_set![3, 4];
+    /// assert!(a.is_disjoint(&b));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_disjoint<const M: usize>(&self, other: &ReSet<T, M>) -> bool {
+        self.intersection(other).next().is_none()
+    }
+}
+
+type IntoIterValue<T> = fn((T, ())) -> T;
+
+impl<T, const N: usize> IntoIterator for ReSet<T, N> {
+    type Item = T;
+    type IntoIter = Map<<ReMap<T, (), N> as IntoIterator>::IntoIter, IntoIterValue<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let value: IntoIterValue<T> = |(x, ())| x;
+        self.map.into_iter().map(value)
+    }
+}
+
+impl<T: Eq, const N: usize> FromIterator<T> for ReSet<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ReSet<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,376 @@
+use crate::CapacityError;
+use core::{
+    array::IntoIter as ArrayIter,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    iter::{Enumerate, FilterMap},
+    ops,
+};
+
+/// A fixed-size slab that hands out stable keys for the elements it stores.
+///
+/// Removing an element frees its key, which will be reused by a later insert.
+/// The keys of all other elements are unchanged.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::ReSlab;
+///
+/// const EMPTY: ReSlab<&str, 4> = ReSlab::new();
+///
+/// let mut slab = EMPTY;
+/// let hello = slab.insert("hello");
+/// let world = slab.insert("world");
+/// assert_eq!(slab[hello], "hello");
+///
+/// assert_eq!(slab.remove(hello), Some("hello"));
+/// assert!(!slab.contains(hello));
+/// assert_eq!(slab[world], "world");
+///
+/// // The freed key is reused
+/// assert_eq!(slab.insert("again"), hello);
+/// ```
+pub struct ReSlab<T, const N: usize> {
+    slots: [Option<T>; N],
+    len: usize,
+    // every slot before this index is occupied
+    next_free: usize,
+}
+
+impl<T: Clone, const N: usize> Clone for ReSlab<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            len: self.len,
+            next_free: self.next_free,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ReSlab<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ReSlab<T, N> {
+    const DEFAULT_SLOT_VALUE: Option<T> = None;
+
+    /// Create a new, empty [`ReSlab`] with room for `N` elements on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let slab = ReSlab::<i32, 3>::new();
+    /// assert!(slab.is_empty());
+    /// assert_eq!(slab.capacity(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            slots: [Self::DEFAULT_SLOT_VALUE; N],
+            len: 0,
+            next_free: 0,
+        }
+    }
+
+    /// Get the key that the next call to [`ReSlab::insert`] will return, or `None` if the slab is full.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 1>::new();
+    /// assert_eq!(slab.vacant_key(), Some(0));
+    /// slab.insert(1);
+    /// assert_eq!(slab.vacant_key(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn vacant_key(&self) -> Option<usize> {
+        (self.next_free..N).find(|&key| self.slots[key].is_none())
+    }
+
+    /// Store an element, returning the key it can be retrieved with.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the slab is full.
+    /// See [`ReSlab::try_insert`] for a non-panicking version.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// let key = slab.insert(5);
+    /// assert_eq!(slab.get(key), Some(&5));
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, val: T) -> usize {
+        match self.try_insert(val) {
+            Ok(key) => key,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Store an element, returning the key it can be retrieved with.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`CapacityError`] if the slab is full.
+    /// The element can be recovered with [`CapacityError::into_inner`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 1>::new();
+    /// assert_eq!(slab.try_insert(1), Ok(0));
+    /// assert_eq!(slab.try_insert(2).unwrap_err().into_inner(), 2);
+    /// ```
+    pub fn try_insert(&mut self, val: T) -> Result<usize, CapacityError<T>> {
+        let Some(key) = self.vacant_key() else {
+            return Err(CapacityError::new(self.len + 1, N).with_inner(val));
+        };
+
+        self.slots[key] = Some(val);
+        self.len += 1;
+        self.next_free = key + 1;
+
+        Ok(key)
+    }
+
+    /// Remove and return the element with the given key, or `None` if there is no such element.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// let key = slab.insert(5);
+    /// assert_eq!(slab.remove(key), Some(5));
+    /// assert_eq!(slab.remove(key), None);
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let val = self.slots.get_mut(key)?.take()?;
+        self.len -= 1;
+        self.next_free = self.next_free.min(key);
+        Some(val)
+    }
+
+    /// Check if there is an element with the given key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// let key = slab.insert(5);
+    /// assert!(slab.contains(key));
+    /// assert!(!slab.contains(key + 1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Get the element with the given key as a reference, returning `None` if there is no such element.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// let key = slab.insert(5);
+    /// assert_eq!(slab.get(key), Some(&5));
+    /// assert_eq!(slab.get(5), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, key: usize) -> Option<&T> {
+        self.slots.get(key).and_then(|slot| slot.as_ref())
+    }
+
+    /// Get the element with the given key as a mutable reference, returning `None` if there is no such element.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// let key = slab.insert(5);
+    /// *slab.get_mut(key).unwrap() = 6;
+    /// assert_eq!(slab[key], 6);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        self.slots.get_mut(key).and_then(|slot| slot.as_mut())
+    }
+
+    /// How many elements are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// slab.insert(5);
+    /// assert_eq!(slab.len(), 1);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let slab = ReSlab::<i32, 3>::new();
+    /// assert!(slab.is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// How many elements can be stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let slab = ReSlab::<i32, 3>::new();
+    /// assert_eq!(slab.capacity(), 3);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove all elements, freeing every key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// slab.insert(5);
+    /// slab.clear();
+    /// assert!(slab.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|x| *x = None);
+        self.len = 0;
+        self.next_free = 0;
+    }
+
+    /// Get an iterator over the stored elements and their keys, in order of key.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// slab.insert(5);
+    /// let key = slab.insert(6);
+    /// slab.insert(7);
+    /// slab.remove(key);
+    ///
+    /// assert_eq!(slab.iter().collect::<Vec<_>>(), vec![(0, &5), (2, &7)]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(key, slot)| slot.as_ref().map(|val| (key, val)))
+    }
+
+    /// Get an iterator over the stored elements and their keys, returning mutable references.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::ReSlab;
+    ///
+    /// let mut slab = ReSlab::<i32, 3>::new();
+    /// slab.insert(5);
+    /// slab.insert(6);
+    ///
+    /// for (key, val) in slab.iter_mut() {
+    ///     *val += key as i32;
+    /// }
+    ///
+    /// assert_eq!(slab.iter().collect::<Vec<_>>(), vec![(0, &5), (1, &7)]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> + '_ {
+        self.slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(key, slot)| slot.as_mut().map(|val| (key, val)))
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for ReSlab<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, key: usize) -> &Self::Output {
+        self.slots[key].as_ref().unwrap()
+    }
+}
+
+impl<T, const N: usize> ops::IndexMut<usize> for ReSlab<T, N> {
+    #[inline]
+    fn index_mut(&mut self, key: usize) -> &mut Self::Output {
+        self.slots[key].as_mut().unwrap()
+    }
+}
+
+type IntoIterSlot<T> = fn((usize, Option<T>)) -> Option<(usize, T)>;
+
+impl<T, const N: usize> IntoIterator for ReSlab<T, N> {
+    type Item = (usize, T);
+    type IntoIter = FilterMap<Enumerate<ArrayIter<Option<T>, N>>, IntoIterSlot<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slots
+            .into_iter()
+            .enumerate()
+            .filter_map(|(key, slot)| slot.map(|val| (key, val)))
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ReSlab<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,99 @@
+use core::mem;
+
+mod sealed {
+    pub trait Sealed<T> {}
+
+    impl<T> Sealed<T> for Option<T> {}
+
+    impl<T: Default> Sealed<T> for T {}
+}
+
+/// The storage for one element of a [`ReArr`](crate::ReArr).
+///
+/// A [`ReArr`](crate::ReArr) tracks which slots hold an element with its length,
+/// so a slot only has to be able to stand in for a missing element.
+///
+/// - [`Option<T>`] works for any `T`, at the cost of a tag for types without a niche.
+///   This is the default.
+/// - `T` itself works for any `T: Default`, filling the unused slots with `T::default()`.
+///   Numbers and other plain types pay nothing extra for this.
+///
+/// This trait is sealed, it is only implemented for these two kinds of slot.
+pub trait Slot<T>: sealed::Sealed<T> {
+    /// Create a slot without an element.
+    fn empty() -> Self;
+
+    /// Create a slot that holds `val`.
+    fn fill(val: T) -> Self;
+
+    /// Get the element in the slot.
+    ///
+    /// This is only called on slots that hold an element.
+    fn get(&self) -> &T;
+
+    /// Get the element in the slot as a mutable reference.
+    ///
+    /// This is only called on slots that hold an element.
+    fn get_mut(&mut self) -> &mut T;
+
+    /// Take the element out of the slot, leaving it empty.
+    ///
+    /// This is only called on slots that hold an element.
+    fn take(&mut self) -> T;
+}
+
+impl<T> Slot<T> for Option<T> {
+    #[inline]
+    fn empty() -> Self {
+        None
+    }
+
+    #[inline]
+    fn fill(val: T) -> Self {
+        Some(val)
+    }
+
+    #[inline]
+    fn get(&self) -> &T {
+        self.as_ref()
+            .unwrap_or_else(|| unreachable!("slot is empty"))
+    }
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut T {
+        self.as_mut()
+            .unwrap_or_else(|| unreachable!("slot is empty"))
+    }
+
+    #[inline]
+    fn take(&mut self) -> T {
+        Self::take(self).unwrap_or_else(|| unreachable!("slot is empty"))
+    }
+}
+
+impl<T: Default> Slot<T> for T {
+    #[inline]
+    fn empty() -> Self {
+        T::default()
+    }
+
+    #[inline]
+    fn fill(val: T) -> Self {
+        val
+    }
+
+    #[inline]
+    fn get(&self) -> &T {
+        self
+    }
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut T {
+        self
+    }
+
+    #[inline]
+    fn take(&mut self) -> T {
+        mem::take(self)
+    }
+}
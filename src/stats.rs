@@ -0,0 +1,313 @@
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::RangeInclusive,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// The number of buckets in a length histogram: one for zero, then one per power of two.
+pub const HISTOGRAM_BUCKETS: usize = usize::BITS as usize + 1;
+
+static GLOBAL: SpillStats = SpillStats::new();
+
+// Bucket 0 holds a length of 0, and bucket `i` holds the lengths in `2^(i - 1)..=2^i - 1`.
+const fn bucket_of(len: usize) -> usize {
+    (usize::BITS - len.leading_zeros()) as usize
+}
+
+const fn bucket_range(bucket: usize) -> RangeInclusive<usize> {
+    if bucket == 0 {
+        return 0..=0;
+    }
+
+    let start = 1 << (bucket - 1);
+    start..=start + (start - 1)
+}
+
+/// Counters that record how [`ComboVec`](crate::ComboVec)s grow, to help pick their inline capacity.
+///
+/// Every [`ComboVec::push`](crate::ComboVec::push) (and everything built on it, like `extend`)
+/// records into the global counters from [`SpillStats::global`].
+/// Use [`ComboVec::push_tracked`](crate::ComboVec::push_tracked) with a `static` of your own to track a single call site.
+///
+/// The counters are relaxed atomics, so they can be shared between threads but a snapshot may be slightly stale.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{ComboVec, SpillStats};
+///
+/// static FRAMES: SpillStats = SpillStats::new();
+///
+/// let mut frame = ComboVec::<u8, 4>::new();
+/// for byte in 0..6 {
+///     frame.push_tracked(byte, &FRAMES);
+/// }
+///
+/// let stats = FRAMES.snapshot();
+/// assert_eq!(stats.pushes(), 6);
+/// assert_eq!(stats.heap_pushes(), 2);
+/// assert_eq!(stats.spills(), 1);
+/// assert_eq!(stats.max_len(), 6);
+/// ```
+#[derive(Debug)]
+pub struct SpillStats {
+    pushes: AtomicUsize,
+    heap_pushes: AtomicUsize,
+    spills: AtomicUsize,
+    max_len: AtomicUsize,
+    histogram: [AtomicUsize; HISTOGRAM_BUCKETS],
+}
+
+impl Default for SpillStats {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpillStats {
+    /// Create a new set of counters, all at zero.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::SpillStats;
+    ///
+    /// static STATS: SpillStats = SpillStats::new();
+    /// assert_eq!(STATS.snapshot().pushes(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            pushes: AtomicUsize::new(0),
+            heap_pushes: AtomicUsize::new(0),
+            spills: AtomicUsize::new(0),
+            max_len: AtomicUsize::new(0),
+            histogram: [const { AtomicUsize::new(0) }; HISTOGRAM_BUCKETS],
+        }
+    }
+
+    /// Get the counters that every [`ComboVec::push`](crate::ComboVec::push) records into.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{combo_vec, SpillStats};
+    ///
+    /// let before = SpillStats::global().snapshot().pushes();
+    /// let mut vec = combo_vec![1, 2, 3];
+    /// vec.push(4);
+    /// assert!(SpillStats::global().snapshot().pushes() > before);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn global() -> &'static Self {
+        &GLOBAL
+    }
+
+    /// Record a push that left a vector with `len` elements and an inline capacity of `inline_capacity`.
+    #[inline]
+    pub(crate) fn record_push(&self, len: usize, inline_capacity: usize) {
+        self.pushes.fetch_add(1, Ordering::Relaxed);
+        if len > inline_capacity {
+            self.heap_pushes.fetch_add(1, Ordering::Relaxed);
+            if len == inline_capacity + 1 {
+                self.spills.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        self.max_len.fetch_max(len, Ordering::Relaxed);
+        self.histogram[bucket_of(len)].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record the pushes that grew a vector from `from` to `to` elements in one go.
+    ///
+    /// This counts the same as pushing every element one at a time,
+    /// but touches each counter and histogram bucket at most once.
+    #[inline]
+    pub(crate) fn record_growth(&self, from: usize, to: usize, inline_capacity: usize) {
+        if to <= from {
+            return;
+        }
+
+        self.pushes.fetch_add(to - from, Ordering::Relaxed);
+        let heap_pushes = to.saturating_sub(from.max(inline_capacity));
+        if heap_pushes != 0 {
+            self.heap_pushes.fetch_add(heap_pushes, Ordering::Relaxed);
+            if from <= inline_capacity {
+                self.spills.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        self.max_len.fetch_max(to, Ordering::Relaxed);
+        for bucket in bucket_of(from + 1)..=bucket_of(to) {
+            let lens = bucket_range(bucket);
+            let count = to.min(*lens.end()) - from.max(*lens.start() - 1);
+            self.histogram[bucket].fetch_add(count, Ordering::Relaxed);
+        }
+    }
+
+    /// Copy the current value of every counter.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{ComboVec, SpillStats};
+    ///
+    /// let stats = SpillStats::new();
+    /// let mut vec = ComboVec::<i32, 2>::new();
+    /// vec.push_tracked(1, &stats);
+    /// vec.push_tracked(2, &stats);
+    ///
+    /// let snapshot = stats.snapshot();
+    /// assert_eq!(snapshot.pushes(), 2);
+    /// assert_eq!(snapshot.spills(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn snapshot(&self) -> SpillStatsSnapshot {
+        SpillStatsSnapshot {
+            pushes: self.pushes.load(Ordering::Relaxed),
+            heap_pushes: self.heap_pushes.load(Ordering::Relaxed),
+            spills: self.spills.load(Ordering::Relaxed),
+            max_len: self.max_len.load(Ordering::Relaxed),
+            histogram: core::array::from_fn(|i| self.histogram[i].load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Set every counter back to zero.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{ComboVec, SpillStats};
+    ///
+    /// let stats = SpillStats::new();
+    /// ComboVec::<i32, 2>::new().push_tracked(1, &stats);
+    /// stats.reset();
+    /// assert_eq!(stats.snapshot().pushes(), 0);
+    /// ```
+    #[inline]
+    pub fn reset(&self) {
+        self.pushes.store(0, Ordering::Relaxed);
+        self.heap_pushes.store(0, Ordering::Relaxed);
+        self.spills.store(0, Ordering::Relaxed);
+        self.max_len.store(0, Ordering::Relaxed);
+        self.histogram
+            .iter()
+            .for_each(|count| count.store(0, Ordering::Relaxed));
+    }
+}
+
+/// A copy of the counters in a [`SpillStats`] at one point in time.
+///
+/// The [`Display`] implementation dumps every counter and the non-empty histogram buckets.
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::{ComboVec, SpillStats};
+///
+/// let stats = SpillStats::new();
+/// let mut vec = ComboVec::<u8, 2>::new();
+/// vec.push_tracked(1, &stats);
+/// vec.push_tracked(2, &stats);
+/// vec.push_tracked(3, &stats);
+///
+/// assert_eq!(
+///     stats.snapshot().to_string(),
+///     "pushes: 3, heap pushes: 1, spills: 1, max len: 3\nlen 1: 1\nlen 2..=3: 2\n",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpillStatsSnapshot {
+    pushes: usize,
+    heap_pushes: usize,
+    spills: usize,
+    max_len: usize,
+    histogram: [usize; HISTOGRAM_BUCKETS],
+}
+
+impl SpillStatsSnapshot {
+    /// How many elements were pushed.
+    #[must_use]
+    #[inline]
+    pub const fn pushes(&self) -> usize {
+        self.pushes
+    }
+
+    /// How many elements were pushed onto the heap because the inline storage was full.
+    #[must_use]
+    #[inline]
+    pub const fn heap_pushes(&self) -> usize {
+        self.heap_pushes
+    }
+
+    /// How many times a vector outgrew its inline storage and spilled onto the heap.
+    #[must_use]
+    #[inline]
+    pub const fn spills(&self) -> usize {
+        self.spills
+    }
+
+    /// The longest length any vector reached.
+    #[must_use]
+    #[inline]
+    pub const fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// The number of pushes that left a vector with each length, bucketed by powers of two.
+    ///
+    /// Bucket 0 holds a length of 0, and bucket `i` holds the lengths in `2^(i - 1)..=2^i - 1`.
+    #[must_use]
+    #[inline]
+    pub const fn histogram(&self) -> &[usize; HISTOGRAM_BUCKETS] {
+        &self.histogram
+    }
+
+    /// Get the non-empty histogram buckets, along with the range of lengths they hold.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::{ComboVec, SpillStats};
+    ///
+    /// let stats = SpillStats::new();
+    /// let mut vec = ComboVec::<u8, 4>::new();
+    /// (0..5).for_each(|i| vec.push_tracked(i, &stats));
+    ///
+    /// let buckets = stats.snapshot().buckets().collect::<Vec<_>>();
+    /// assert_eq!(buckets, [(1..=1, 1), (2..=3, 2), (4..=7, 2)]);
+    /// ```
+    #[inline]
+    pub fn buckets(&self) -> impl Iterator<Item = (RangeInclusive<usize>, usize)> + '_ {
+        self.histogram
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count != 0)
+            .map(|(bucket, &count)| (bucket_range(bucket), count))
+    }
+}
+
+impl Display for SpillStatsSnapshot {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(
+            f,
+            "pushes: {}, heap pushes: {}, spills: {}, max len: {}",
+            self.pushes, self.heap_pushes, self.spills, self.max_len
+        )?;
+
+        for (lens, count) in self.buckets() {
+            if lens.start() == lens.end() {
+                writeln!(f, "len {}: {count}", lens.start())?;
+            } else {
+                writeln!(f, "len {lens:?}: {count}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
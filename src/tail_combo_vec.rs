@@ -0,0 +1,544 @@
+use crate::ReRing;
+use alloc::vec::{IntoIter as VecIter, Vec};
+use core::{
+    array::IntoIter as ArrayIter,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter::{Chain, Flatten},
+    ops,
+};
+
+/// A [`ComboVec`](crate::ComboVec) that spills its oldest elements onto the heap, keeping the newest ones on the stack.
+///
+/// The elements are ordered with everything on the heap first, followed by everything on the stack.
+/// The stack is a [`ReRing`] that always holds the newest `N` elements, or all of them if there are fewer.
+/// Once it is full, each push moves only the oldest element on the stack onto the heap,
+/// and each pop moves the newest element on the heap back onto the stack.
+/// This keeps the hot end of the array on the stack while pushing and popping stay O(1).
+///
+/// ## Examples
+///
+/// ```rust
+/// use combo_vec::TailComboVec;
+///
+/// let mut history = TailComboVec::<i32, 4>::new();
+/// history.extend(1..=5);
+///
+/// assert_eq!(history.to_vec(), vec![1, 2, 3, 4, 5]);
+/// assert_eq!(history.heap_len(), 1);
+/// assert_eq!(history.stack_len(), 4);
+/// assert_eq!(history.last(), Some(&5));
+/// ```
+pub struct TailComboVec<T, const N: usize> {
+    pub(crate) vec: Vec<T>,
+    pub(crate) ring: ReRing<T, N>,
+}
+
+impl<T: Clone, const N: usize> Clone for TailComboVec<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+            ring: self.ring.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for TailComboVec<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq + Eq, const N: usize> Eq for TailComboVec<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for TailComboVec<T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter().for_each(|x| x.hash(state));
+    }
+}
+
+impl<T, const N: usize> Default for TailComboVec<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> TailComboVec<T, N> {
+    /// Create a new, empty [`TailComboVec`] with the ability for the newest `N` elements to be allocated on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// const EMPTY: TailComboVec<String, 8> = TailComboVec::new();
+    ///
+    /// let my_tail_vec = EMPTY;
+    /// assert!(my_tail_vec.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            vec: Vec::new(),
+            ring: ReRing::new(),
+        }
+    }
+
+    // Move the newest elements on the heap onto the stack until it is full again.
+    fn fill_stack_from_heap(&mut self) {
+        while self.ring.len() < N {
+            match self.vec.pop() {
+                Some(val) => self.ring.push_oldest(val),
+                None => break,
+            }
+        }
+    }
+
+    /// Push an element to the end of the array.
+    ///
+    /// If the stack is full, its oldest element is moved onto the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = TailComboVec::<i32, 2>::new();
+    /// my_tail_vec.push(1);
+    /// my_tail_vec.push(2);
+    /// assert!(!my_tail_vec.spilled());
+    ///
+    /// my_tail_vec.push(3);
+    /// assert!(my_tail_vec.spilled());
+    /// assert_eq!(my_tail_vec.heap_len(), 1);
+    /// assert_eq!(my_tail_vec.to_vec(), vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, val: T) {
+        if let Some(oldest) = self.ring.push(val) {
+            self.vec.push(oldest);
+        }
+    }
+
+    /// Remove the last element from the array and return it, or None if it is empty.
+    ///
+    /// The newest element on the heap, if any, is moved onto the stack to take its place.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=5).collect::<TailComboVec<i32, 2>>();
+    /// assert_eq!(my_tail_vec.pop(), Some(5));
+    /// assert_eq!(my_tail_vec.heap_len(), 2);
+    /// assert_eq!(my_tail_vec.pop(), Some(4));
+    /// assert_eq!(my_tail_vec.pop(), Some(3));
+    /// assert_eq!(my_tail_vec.heap_len(), 0);
+    /// assert_eq!(my_tail_vec.stack_len(), 2);
+    /// assert_eq!(my_tail_vec.pop(), Some(2));
+    /// assert_eq!(my_tail_vec.pop(), Some(1));
+    /// assert_eq!(my_tail_vec.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let val = self.ring.pop_latest().or_else(|| self.vec.pop());
+        self.fill_stack_from_heap();
+        val
+    }
+
+    /// Get any element from the array as a reference, returning `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let my_tail_vec = (1..=3).collect::<TailComboVec<i32, 2>>();
+    /// assert_eq!(my_tail_vec.get(0), Some(&1));
+    /// assert_eq!(my_tail_vec.get(2), Some(&3));
+    /// assert_eq!(my_tail_vec.get(3), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        let heap_len = self.vec.len();
+        if idx < heap_len {
+            self.vec.get(idx)
+        } else {
+            self.ring.get(idx - heap_len)
+        }
+    }
+
+    /// Get any element from the array as a mutable reference, `None` if out of bounds.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=3).collect::<TailComboVec<i32, 2>>();
+    ///
+    /// if let Some(x) = my_tail_vec.get_mut(0) {
+    ///     *x = 4;
+    /// }
+    ///
+    /// assert_eq!(my_tail_vec.to_vec(), vec![4, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        let heap_len = self.vec.len();
+        if idx < heap_len {
+            self.vec.get_mut(idx)
+        } else {
+            self.ring.get_mut(idx - heap_len)
+        }
+    }
+
+    /// Whether or not where are any elements allocated on the heap instead of the stack
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=3).collect::<TailComboVec<i32, 3>>();
+    /// assert_eq!(my_tail_vec.spilled(), false);
+    ///
+    /// my_tail_vec.push(4);
+    /// assert_eq!(my_tail_vec.spilled(), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        !self.vec.is_empty()
+    }
+
+    /// How many of the newest elements are currently stored on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=3).collect::<TailComboVec<i32, 4>>();
+    /// assert_eq!(my_tail_vec.stack_len(), 3);
+    /// my_tail_vec.extend([4, 5]);
+    /// assert_eq!(my_tail_vec.stack_len(), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn stack_len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// How many of the oldest elements are currently stored on the heap.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=4).collect::<TailComboVec<i32, 4>>();
+    /// assert_eq!(my_tail_vec.heap_len(), 0);
+    /// my_tail_vec.push(5);
+    /// assert_eq!(my_tail_vec.heap_len(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn heap_len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// How many elements are currently stored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=3).collect::<TailComboVec<i32, 3>>();
+    /// assert_eq!(my_tail_vec.len(), 3);
+    /// my_tail_vec.push(4);
+    /// assert_eq!(my_tail_vec.len(), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len() + self.ring.len()
+    }
+
+    /// How many elements can be stored on the stack.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let my_tail_vec = TailComboVec::<i32, 3>::new();
+    /// assert_eq!(my_tail_vec.stack_capacity(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn stack_capacity(&self) -> usize {
+        N
+    }
+
+    /// Check if the array is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = TailComboVec::<i32, 3>::new();
+    /// assert!(my_tail_vec.is_empty());
+    /// my_tail_vec.push(1);
+    /// assert!(!my_tail_vec.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the first (oldest) element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let my_tail_vec = (1..=3).collect::<TailComboVec<i32, 2>>();
+    /// assert_eq!(my_tail_vec.first(), Some(&1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.vec.first().or_else(|| self.ring.oldest())
+    }
+
+    /// Get the last (newest) element, returning `None` if there are no elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let my_tail_vec = (1..=3).collect::<TailComboVec<i32, 2>>();
+    /// assert_eq!(my_tail_vec.last(), Some(&3));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.ring.latest().or_else(|| self.vec.last())
+    }
+
+    /// Reduces the length to the given length, dropping the newest elements.
+    ///
+    /// If `len` is greater than the current length, this has no effect.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=5).collect::<TailComboVec<i32, 2>>();
+    /// my_tail_vec.truncate(2);
+    /// assert_eq!(my_tail_vec.to_vec(), vec![1, 2]);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        let heap_len = self.vec.len();
+        if len > self.len() {
+            // do nothing
+        } else if len < heap_len {
+            self.ring.clear();
+            self.vec.truncate(len);
+        } else {
+            for _ in len..self.len() {
+                self.ring.pop_latest();
+            }
+        }
+
+        self.fill_stack_from_heap();
+    }
+
+    /// Remove all elements from the array.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=5).collect::<TailComboVec<i32, 2>>();
+    /// my_tail_vec.clear();
+    /// assert!(my_tail_vec.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+        self.ring.clear();
+    }
+
+    /// Get an iterator over the elements of the array, from oldest to newest.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let my_tail_vec = (1..=3).collect::<TailComboVec<i32, 2>>();
+    /// let mut iter = my_tail_vec.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.vec.iter().chain(self.ring.iter())
+    }
+
+    /// Get an iterator over the elements of the array, returning mutable references.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = (1..=3).collect::<TailComboVec<i32, 2>>();
+    /// for i in my_tail_vec.iter_mut() {
+    ///    *i += 1;
+    /// }
+    /// assert_eq!(my_tail_vec.to_vec(), vec![2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.vec.iter_mut().chain(self.ring.iter_mut())
+    }
+
+    /// Extend this array with all the elements from the given iterator.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let mut my_tail_vec = TailComboVec::<i32, 2>::new();
+    /// my_tail_vec.extend(vec![1, 2, 3, 4]);
+    /// assert_eq!(my_tail_vec.to_vec(), vec![1, 2, 3, 4]);
+    /// assert_eq!(my_tail_vec.last(), Some(&4));
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| self.push(val));
+    }
+
+    /// Convert into a [`Vec`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let my_tail_vec = (1..=3).collect::<TailComboVec<i32, 2>>();
+    /// assert_eq!(my_tail_vec.into_vec(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.vec.extend(self.ring);
+        self.vec
+    }
+}
+
+impl<T: Clone, const N: usize> TailComboVec<T, N> {
+    /// Clone all the elements into a [`Vec`], from oldest to newest.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use combo_vec::TailComboVec;
+    ///
+    /// let my_tail_vec = (1..=3).collect::<TailComboVec<i32, 2>>();
+    /// assert_eq!(my_tail_vec.to_vec(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for TailComboVec<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &Self::Output {
+        let heap_len = self.vec.len();
+        if idx < heap_len {
+            &self.vec[idx]
+        } else {
+            &self.ring[idx - heap_len]
+        }
+    }
+}
+
+impl<T, const N: usize> ops::IndexMut<usize> for TailComboVec<T, N> {
+    #[inline]
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        let heap_len = self.vec.len();
+        if idx < heap_len {
+            &mut self.vec[idx]
+        } else {
+            &mut self.ring[idx - heap_len]
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for TailComboVec<T, N> {
+    type Item = T;
+    type IntoIter = Chain<VecIter<T>, Flatten<ArrayIter<Option<T>, N>>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter().chain(self.ring)
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for TailComboVec<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tail_vec = Self::new();
+        tail_vec.extend(iter);
+        tail_vec
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for TailComboVec<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("TailComboVec")
+            .field("vec", &self.vec)
+            .field("ring", &self.ring)
+            .finish()
+    }
+}
+
+impl<T: Debug, const N: usize> Display for TailComboVec<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list()
+            .entries(&self.vec)
+            .entries(self.ring.iter())
+            .finish()
+    }
+}
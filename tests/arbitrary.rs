@@ -0,0 +1,39 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use combo_vec::ReArr;
+
+#[test]
+fn re_arr_stays_within_capacity() {
+    let data = (0..=255).collect::<Vec<u8>>();
+    let mut u = Unstructured::new(&data);
+
+    while !u.is_empty() {
+        let arr = ReArr::<u8, 4>::arbitrary(&mut u).unwrap();
+        assert!(arr.len() <= 4);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn combo_vec_covers_both_sides_of_capacity() {
+    use combo_vec::ComboVec;
+
+    let data = (0..1024u32)
+        .flat_map(|i| i.wrapping_mul(2_654_435_761).to_le_bytes())
+        .collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let (mut inline, mut spilled) = (false, false);
+
+    while !u.is_empty() {
+        let vec = ComboVec::<u8, 4>::arbitrary(&mut u).unwrap();
+        assert!(vec.len() <= 9);
+        if vec.spilled() {
+            spilled = true;
+        } else {
+            inline = true;
+        }
+    }
+
+    assert!(inline && spilled);
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "bincode")]
+
+use bincode::{
+    config::{self, Configuration},
+    error::DecodeError,
+};
+use combo_vec::{re_arr, ReArr};
+
+const CONFIG: Configuration = config::standard();
+
+fn encode<T: bincode::Encode + ?Sized>(val: &T) -> Vec<u8> {
+    let mut buf = [0; 256];
+    let len = bincode::encode_into_slice(val, &mut buf, CONFIG).unwrap();
+    buf[..len].to_vec()
+}
+
+#[test]
+fn re_arr_round_trip() {
+    let arr = re_arr![1u16, 2, 300; None, None];
+    let bytes = encode(&arr);
+    assert_eq!(bytes, encode(&[1u16, 2, 300][..]));
+
+    let (decoded, read): (ReArr<u16, 5>, _) = bincode::decode_from_slice(&bytes, CONFIG).unwrap();
+    assert_eq!(decoded, arr);
+    assert_eq!(read, bytes.len());
+
+    let strs = encode(&["a", "b"][..]);
+    let (borrowed, _): (ReArr<&str, 2>, _) =
+        bincode::borrow_decode_from_slice(&strs, CONFIG).unwrap();
+    assert_eq!(borrowed, re_arr!["a", "b"]);
+}
+
+#[test]
+fn re_arr_rejects_overlong_input() {
+    let bytes = encode(&[1u8, 2, 3][..]);
+    let err = bincode::decode_from_slice::<ReArr<u8, 2>, _>(&bytes, CONFIG).unwrap_err();
+    assert!(matches!(err, DecodeError::Other(_)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn combo_vec_round_trip() {
+    use combo_vec::{combo_vec, ComboVec};
+
+    let mut vec = combo_vec![10u64, 20];
+    vec.extend([u64::MAX, 40]);
+    let bytes = encode(&vec);
+    assert_eq!(bytes, encode(&vec.to_vec()[..]));
+
+    let (decoded, _): (ComboVec<u64, 2>, _) = bincode::decode_from_slice(&bytes, CONFIG).unwrap();
+    assert_eq!(decoded, vec);
+    assert!(decoded.spilled());
+
+    // the stack capacity isn't part of the encoding
+    let (decoded, _): (ComboVec<u64, 8>, _) = bincode::decode_from_slice(&bytes, CONFIG).unwrap();
+    assert_eq!(decoded.to_vec(), vec.to_vec());
+}
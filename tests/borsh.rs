@@ -0,0 +1,40 @@
+#![cfg(feature = "borsh")]
+
+use combo_vec::{re_arr, ReArr};
+
+#[test]
+fn re_arr_round_trip() {
+    let arr = re_arr![1u16, 2, 3; None, None];
+    let bytes = borsh::to_vec(&arr).unwrap();
+    assert_eq!(bytes, borsh::to_vec(&vec![1u16, 2, 3]).unwrap());
+
+    let decoded: ReArr<u16, 5> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, arr);
+}
+
+#[test]
+fn re_arr_rejects_overlong_input() {
+    let bytes = borsh::to_vec(&vec![1u8, 2, 3]).unwrap();
+    let err = borsh::from_slice::<ReArr<u8, 2>>(&bytes).unwrap_err();
+    assert_eq!(err.kind(), borsh::io::ErrorKind::InvalidData);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn combo_vec_round_trip() {
+    use combo_vec::{combo_vec, ComboVec};
+
+    let mut vec = combo_vec![String::from("a"), String::from("b")];
+    vec.push(String::from("spilled"));
+    let bytes = borsh::to_vec(&vec).unwrap();
+    assert_eq!(bytes, borsh::to_vec(&vec.to_vec()).unwrap());
+
+    let decoded: ComboVec<String, 2> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, vec);
+    assert!(decoded.spilled());
+
+    // the stack capacity isn't part of the encoding
+    let decoded: ComboVec<String, 8> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded.to_vec(), vec.to_vec());
+    assert!(!decoded.spilled());
+}
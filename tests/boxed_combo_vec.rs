@@ -0,0 +1,273 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::{assert_stack_size, combo_vec, BoxedComboVec, ComboVec};
+
+assert_stack_size!(BoxedComboVec<u64, 1_000_000>, 64);
+assert_stack_size!(ComboVec<u8, 16>, 64);
+
+#[test]
+fn lazy_inline_storage() {
+    let mut boxed = BoxedComboVec::<String, 3>::new();
+    assert!(!boxed.is_inline_allocated());
+    assert_eq!(boxed.pop(), None);
+    assert_eq!(boxed.get(0), None);
+    assert_eq!(boxed.iter().count(), 0);
+    assert!(!boxed.is_inline_allocated());
+
+    boxed.push(String::from("hello"));
+    assert!(boxed.is_inline_allocated());
+    assert_eq!(boxed.len(), 1);
+    assert_eq!(boxed[0], "hello");
+}
+
+#[test]
+fn large_inline_capacity_on_small_stack() {
+    // The inline storage alone is 8 MiB, far more than the thread's stack.
+    let handle = std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let mut boxed = BoxedComboVec::<u64, { 512 * 1024 }>::new();
+            boxed.extend(0..600 * 1024);
+            let copy = boxed.clone();
+            (copy.stack_len(), copy.heap_len(), copy.last().copied())
+        })
+        .unwrap();
+
+    assert_eq!(
+        handle.join().unwrap(),
+        (512 * 1024, 88 * 1024, Some(600 * 1024 - 1))
+    );
+}
+
+#[test]
+fn matches_combo_vec() {
+    let mut boxed = BoxedComboVec::<i32, 4>::new();
+    let mut combo = ComboVec::<i32, 4>::new();
+
+    for i in 0..10 {
+        boxed.push(i);
+        combo.push(i);
+        assert_eq!(boxed.len(), combo.len());
+        assert_eq!(boxed.stack_len(), combo.stack_len());
+        assert_eq!(boxed.spilled(), combo.spilled());
+    }
+
+    boxed.extend_from_slice(&[10, 11]);
+    combo.extend_from_slice(&[10, 11]);
+    assert_eq!(boxed.to_vec(), combo.to_vec());
+
+    for len in [8, 5, 4, 2, 0] {
+        boxed.truncate(len);
+        combo.truncate(len);
+        assert_eq!(boxed.to_vec(), combo.to_vec());
+        assert_eq!(boxed.last(), combo.last());
+    }
+
+    assert!(boxed.is_empty());
+    assert!(boxed.is_inline_allocated());
+
+    // A slice that starts on the stack and spills onto the heap.
+    boxed.push(1);
+    combo.push(1);
+    boxed.extend_from_slice(&[2, 3, 4, 5, 6]);
+    combo.extend_from_slice(&[2, 3, 4, 5, 6]);
+    assert_eq!(boxed.to_vec(), combo.to_vec());
+    assert_eq!(boxed.stack_len(), combo.stack_len());
+}
+
+#[test]
+fn get_and_index_mut() {
+    let mut boxed = (1..=5).collect::<BoxedComboVec<i32, 3>>();
+    *boxed.get_mut(1).unwrap() = 20;
+    boxed[4] = 50;
+    boxed.iter_mut().for_each(|x| *x += 1);
+
+    assert_eq!(boxed.to_vec(), vec![2, 21, 4, 5, 51]);
+    assert_eq!(boxed.first(), Some(&2));
+    assert_eq!(boxed.get(5), None);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn index_out_of_bounds() {
+    let boxed = (1..=2).collect::<BoxedComboVec<i32, 3>>();
+    let _ = boxed[2];
+}
+
+#[test]
+fn into_iter_owned() {
+    let boxed = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(String::from)
+        .collect::<BoxedComboVec<String, 3>>();
+
+    assert_eq!(boxed.clone().into_vec(), ["a", "b", "c", "d"]);
+    assert_eq!(boxed.into_iter().rev().collect::<String>(), "dcba");
+    assert_eq!(BoxedComboVec::<u8, 3>::new().into_iter().count(), 0);
+}
+
+#[test]
+fn combo_vec_round_trip() {
+    let mut combo: ComboVec<i32, 3> = combo_vec![1, 2, 3];
+    combo.push(4);
+    let boxed = BoxedComboVec::from(combo.clone());
+    assert_eq!(boxed.stack_len(), 3);
+    assert_eq!(boxed.heap_len(), 1);
+    assert_eq!(ComboVec::from(boxed), combo);
+
+    let empty = BoxedComboVec::from(ComboVec::<i32, 3>::new());
+    assert!(!empty.is_inline_allocated());
+    assert_eq!(empty.into_combo_vec(), ComboVec::new());
+}
+
+#[test]
+fn eq_hash_and_fmt() {
+    use std::collections::HashSet;
+
+    let a = (1..=4).collect::<BoxedComboVec<i32, 2>>();
+    let mut b = BoxedComboVec::<i32, 2>::default();
+    b.extend([1, 2, 3, 4]);
+    assert_eq!(a, b);
+
+    let set = [a, b].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 1);
+
+    let c = (1..=4).collect::<BoxedComboVec<i32, 2>>();
+    assert_eq!(c.to_string(), "[1, 2, 3, 4]");
+    assert_eq!(
+        format!("{c:?}"),
+        "BoxedComboVec { stack: [Some(1), Some(2)], vec: [3, 4], .. }"
+    );
+}
+
+#[test]
+fn editing_matches_combo_vec() {
+    let mut boxed = (0..8).collect::<BoxedComboVec<i32, 4>>();
+    let mut combo = (0..8).collect::<ComboVec<i32, 4>>();
+
+    let check = |boxed: &BoxedComboVec<i32, 4>, combo: &ComboVec<i32, 4>| {
+        assert_eq!(boxed.to_vec(), combo.to_vec());
+        assert_eq!(boxed.stack_len(), combo.stack_len());
+        assert_eq!(boxed.heap_len(), combo.heap_len());
+    };
+
+    for (index, val) in [(0, 10), (4, 11), (10, 12), (3, 13)] {
+        boxed.insert(index, val);
+        combo.insert(index, val);
+        check(&boxed, &combo);
+    }
+
+    for index in [1, 5, 0, 8] {
+        assert_eq!(boxed.remove(index), combo.remove(index));
+        check(&boxed, &combo);
+    }
+
+    for index in [2, 6, 0] {
+        assert_eq!(boxed.swap_remove(index), combo.swap_remove(index));
+        check(&boxed, &combo);
+    }
+
+    boxed.swap(0, 4);
+    combo.swap(0, 4);
+    boxed.swap(4, 1);
+    combo.swap(4, 1);
+    check(&boxed, &combo);
+
+    *boxed.first_mut().unwrap() += 100;
+    *combo.first_mut().unwrap() += 100;
+    *boxed.last_mut().unwrap() += 100;
+    *combo.last_mut().unwrap() += 100;
+    check(&boxed, &combo);
+
+    boxed.retain(|x| x % 3 != 0);
+    combo.retain(|x| x % 3 != 0);
+    check(&boxed, &combo);
+
+    boxed.resize(9, 7);
+    combo.resize(9, 7);
+    check(&boxed, &combo);
+
+    boxed.dedup();
+    combo.dedup();
+    check(&boxed, &combo);
+
+    boxed.resize_with(2, Default::default);
+    combo.resize_with(2, Default::default);
+    check(&boxed, &combo);
+
+    boxed.clone_from_slice(&[1, 1, 2, 2, 2, 3]);
+    combo.clone_from_slice(&[1, 1, 2, 2, 2, 3]);
+    boxed.dedup_by_key(|x| *x / 2);
+    combo.dedup_by_key(|x| *x / 2);
+    check(&boxed, &combo);
+}
+
+#[test]
+fn dedup_across_the_heap_boundary() {
+    let mut boxed = [1, 2, 2, 2, 2, 3]
+        .into_iter()
+        .collect::<BoxedComboVec<i32, 3>>();
+    boxed.dedup();
+    assert_eq!(boxed.to_vec(), vec![1, 2, 3]);
+    assert!(!boxed.spilled());
+}
+
+#[test]
+fn split_append_and_capacity() {
+    let mut boxed = (0..10).collect::<BoxedComboVec<i32, 4>>();
+    let mut combo = (0..10).collect::<ComboVec<i32, 4>>();
+
+    for at in [8, 3, 0] {
+        let mut boxed_tail = boxed.split_off(at);
+        let mut combo_tail = combo.split_off(at);
+        assert_eq!(boxed_tail.to_vec(), combo_tail.to_vec());
+        assert_eq!(boxed_tail.stack_len(), combo_tail.stack_len());
+        assert_eq!(boxed.to_vec(), combo.to_vec());
+
+        boxed.append(&mut boxed_tail);
+        combo.append(&mut combo_tail);
+        assert!(boxed_tail.is_empty());
+        assert_eq!(boxed.to_vec(), combo.to_vec());
+        assert_eq!(boxed.stack_len(), combo.stack_len());
+    }
+
+    boxed.reserve(20);
+    assert!(boxed.heap_capacity() >= 26);
+    assert_eq!(boxed.capacity(), boxed.heap_capacity() + 4);
+
+    let bigger: BoxedComboVec<i32, 16> = boxed.clone().into_capacity();
+    assert_eq!(bigger.to_vec(), combo.to_vec());
+    assert!(!bigger.spilled());
+
+    let smaller: BoxedComboVec<i32, 2> = boxed.into_capacity();
+    assert_eq!(smaller.to_vec(), combo.to_vec());
+    assert_eq!(smaller.heap_len(), 8);
+
+    let empty: BoxedComboVec<i32, 8> = BoxedComboVec::<i32, 2>::new().into_capacity();
+    assert!(!empty.is_inline_allocated());
+}
+
+#[test]
+fn slice_views_match_combo_vec() {
+    let boxed = (1..=7).collect::<BoxedComboVec<i32, 3>>();
+    let combo = (1..=7).collect::<ComboVec<i32, 3>>();
+
+    assert_eq!(boxed.slice(2..5), combo.slice(2..5));
+    assert!(boxed.chunks(3).eq(combo.chunks(3)));
+    assert!(boxed.chunks_exact(2).eq(combo.chunks_exact(2)));
+    assert!(boxed.rchunks(3).eq(combo.rchunks(3)));
+    assert!(boxed.windows(4).eq(combo.windows(4)));
+    assert!(boxed.split(|x| x % 3 == 0).eq(combo.split(|x| x % 3 == 0)));
+    assert!(boxed
+        .chunk_by(|a, b| a / 3 == b / 3)
+        .eq(combo.chunk_by(|a, b| a / 3 == b / 3)));
+
+    assert_eq!(boxed.ref_vec(), combo.ref_vec());
+    assert_eq!(boxed.join("-"), combo.join("-"));
+    assert_eq!(
+        BoxedComboVec::<i32, 4>::concat(&[boxed.clone(), boxed.clone()]).to_vec(),
+        ComboVec::<i32, 4>::concat(&[combo.clone(), combo]).to_vec()
+    );
+    assert!(BoxedComboVec::<i32, 3>::new().slice(..).is_empty());
+    assert!(boxed > BoxedComboVec::new());
+}
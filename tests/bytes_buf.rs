@@ -0,0 +1,103 @@
+#![cfg(feature = "bytes")]
+
+use bytes::{Buf, BufMut};
+use combo_vec::{re_arr, ReArr, ReArrBuf};
+
+#[test]
+fn re_arr_buf_reads_in_order() {
+    let mut buf = ReArrBuf::new(re_arr![1u8, 2, 3, 4, 5; None, None]);
+
+    assert_eq!(buf.remaining(), 5);
+    assert_eq!(buf.get_u8(), 1);
+    assert_eq!(buf.get_u16_le(), 0x0302);
+    assert_eq!(buf.chunk(), &[4, 5]);
+
+    let mut rest = [0; 2];
+    buf.copy_to_slice(&mut rest);
+    assert_eq!(rest, [4, 5]);
+    assert!(!buf.has_remaining());
+}
+
+#[test]
+#[should_panic(expected = "cannot advance past the end of the buffer")]
+fn re_arr_buf_advance_past_end() {
+    let mut buf = ReArrBuf::new(re_arr![1u8, 2]);
+    buf.advance(3);
+}
+
+#[test]
+fn re_arr_buf_mut_fills_free_slots() {
+    let mut arr = re_arr![1u8; None, None, None, None];
+
+    let remaining = arr.with_buf_mut(|buf| {
+        buf.put_u16_le(0x0302);
+        buf.remaining_mut()
+    });
+    assert_eq!(remaining, 2);
+    assert!(arr.iter().eq(&[1, 2, 3]));
+
+    // Dense slots work the same way.
+    let mut dense = ReArr::<u8, 2, u8>::default();
+    dense.with_buf_mut(|buf| buf.put_u8(9));
+    assert_eq!(dense.as_slice(), &[9]);
+
+    let mut buf = ReArrBuf::new(arr);
+    assert_eq!(buf.get_u8(), 1);
+}
+
+#[test]
+#[should_panic(expected = "advance out of bounds")]
+fn re_arr_buf_mut_is_limited() {
+    let mut arr = ReArr::<u8, 2>::new();
+    arr.with_buf_mut(|buf| buf.put_u32(1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn combo_vec_buf_mut_grows_into_heap() {
+    use combo_vec::{combo_vec, ComboVec};
+
+    let mut frame = combo_vec![1u8; None, None];
+    frame.with_buf_mut(|buf| {
+        assert!(buf.remaining_mut() > 2);
+        buf.put_slice(&[2, 3, 4, 5]);
+    });
+    assert_eq!(frame.stack_len(), 3);
+    assert_eq!(frame.to_vec(), vec![1, 2, 3, 4, 5]);
+
+    // Writing after a spill appends to the heap, even if the stack has room again.
+    let mut spilled = (0..6).collect::<ComboVec<u8, 4>>();
+    spilled.remove(0);
+    spilled.with_buf_mut(|buf| buf.put_u8(6));
+    assert_eq!(spilled.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn combo_vec_buf_crosses_into_heap() {
+    use combo_vec::{ComboVec, ComboVecBuf};
+
+    let mut buf = ComboVecBuf::new((0..10).collect::<ComboVec<u8, 4>>());
+
+    assert_eq!(buf.remaining(), 10);
+    assert_eq!(buf.chunk(), &[0, 1, 2, 3]);
+
+    // A read that straddles the stack and the heap.
+    assert_eq!(buf.get_u64(), 0x0001_0203_0405_0607);
+    assert_eq!(buf.chunk(), &[8, 9]);
+    assert_eq!(buf.remaining(), 2);
+
+    buf.advance(2);
+    assert!(!buf.has_remaining());
+    assert!(buf.chunk().is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn combo_vec_buf_without_heap() {
+    use combo_vec::{combo_vec, ComboVecBuf};
+
+    let mut buf = ComboVecBuf::from(combo_vec![7u8, 8]);
+    assert_eq!(buf.copy_to_bytes(2).as_ref(), &[7, 8]);
+    assert!(buf.chunk().is_empty());
+}
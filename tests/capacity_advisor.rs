@@ -0,0 +1,73 @@
+#![cfg(feature = "stats")]
+
+use combo_vec::{combo_vec_size_of, CapacityAdvisor, ComboVec};
+
+#[test]
+fn percentiles() {
+    let mut advisor = CapacityAdvisor::new();
+    advisor.extend((0..90).map(|i| i % 8));
+    advisor.extend([20; 9]);
+    advisor.record(500);
+
+    assert_eq!(advisor.samples(), 100);
+    assert_eq!(advisor.max_len(), Some(500));
+    assert_eq!(advisor.percentile(50), Some(4));
+    assert_eq!(advisor.percentile(90), Some(7));
+    assert_eq!(advisor.percentile(95), Some(20));
+    assert_eq!(advisor.percentile(99), Some(20));
+    assert_eq!(advisor.percentile(100), Some(500));
+    assert_eq!(advisor.percentile(0), Some(0));
+}
+
+#[test]
+fn recommendation() {
+    let advisor = [4, 4, 4, 5, 12].into_iter().collect::<CapacityAdvisor>();
+
+    let rec = advisor.recommend::<u16>(60).unwrap();
+    assert_eq!(rec.inline_capacity(), 4);
+    assert_eq!(rec.covered(), 3);
+    assert_eq!(rec.samples(), 5);
+    assert_eq!(rec.stack_bytes(), size_of::<ComboVec<u16, 4>>());
+    assert_eq!(
+        rec.to_string(),
+        format!(
+            "N = 4 keeps 3 of 5 samples (60%) on the stack in {} bytes",
+            rec.stack_bytes()
+        )
+    );
+
+    let rec = advisor.recommend::<u16>(80).unwrap();
+    assert_eq!(rec.inline_capacity(), 5);
+    assert_eq!(rec.stack_bytes(), size_of::<ComboVec<u16, 5>>());
+}
+
+#[test]
+#[should_panic(expected = "quantile must be a fraction between 0 and 1")]
+fn percentile_over_100() {
+    let _ = CapacityAdvisor::from_iter([1, 2]).percentile(101);
+}
+
+#[test]
+fn size_estimates_match_layout() {
+    macro_rules! check {
+        ($($t:ty),*) => {$(
+            assert_eq!(combo_vec_size_of::<$t>(0), size_of::<ComboVec<$t, 0>>());
+            assert_eq!(combo_vec_size_of::<$t>(1), size_of::<ComboVec<$t, 1>>());
+            assert_eq!(combo_vec_size_of::<$t>(3), size_of::<ComboVec<$t, 3>>());
+            assert_eq!(combo_vec_size_of::<$t>(64), size_of::<ComboVec<$t, 64>>());
+        )*};
+    }
+
+    check!(
+        (),
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        [u8; 3],
+        String,
+        Box<u8>,
+        (u8, u32)
+    );
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::{combo_vec, ComboBinaryHeap, ComboBinaryHeapPeekMut};
+
+const EMPTY_HEAP: ComboBinaryHeap<i32, 3> = ComboBinaryHeap::new();
+
+#[test]
+fn push_pop_across_boundary() {
+    let mut heap = EMPTY_HEAP;
+    heap.extend([5, 1, 8, 3, 9, 2, 7]);
+    assert!(heap.spilled());
+
+    let mut popped = Vec::new();
+    while let Some(x) = heap.pop() {
+        popped.push(x);
+    }
+
+    assert_eq!(popped, vec![9, 8, 7, 5, 3, 2, 1]);
+    assert!(!heap.spilled());
+}
+
+#[test]
+fn heapify_and_sort() {
+    let heap = ComboBinaryHeap::from(combo_vec![4, 8, 1, 9, 3, 3, 6]);
+    assert_eq!(heap.peek(), Some(&9));
+    assert_eq!(
+        heap.clone().into_sorted_vec().to_vec(),
+        vec![1, 3, 3, 4, 6, 8, 9]
+    );
+
+    let mut vec = heap.into_vec().into_vec();
+    vec.sort_unstable();
+    assert_eq!(vec, vec![1, 3, 3, 4, 6, 8, 9]);
+}
+
+#[test]
+fn peek_mut_restores_order() {
+    let mut heap: ComboBinaryHeap<i32, 2> = [10, 20, 30, 40].into_iter().collect();
+
+    *heap.peek_mut().unwrap() = 5;
+    assert_eq!(heap.peek(), Some(&30));
+
+    assert_eq!(ComboBinaryHeapPeekMut::pop(heap.peek_mut().unwrap()), 30);
+    assert_eq!(heap.drain().count(), 3);
+    assert!(heap.is_empty());
+    assert!(heap.peek_mut().is_none());
+}
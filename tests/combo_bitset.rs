@@ -0,0 +1,49 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::ComboBitSet;
+
+const EMPTY_SET: ComboBitSet<2> = ComboBitSet::new();
+
+#[test]
+fn insert_remove_across_boundary() {
+    let mut set = EMPTY_SET;
+    assert!(set.insert(0));
+    assert!(set.insert(127));
+    assert!(!set.spilled());
+    assert!(set.insert(128));
+    assert!(set.insert(5000));
+    assert!(set.spilled());
+    assert_eq!(set.count_ones(), 4);
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 127, 128, 5000]);
+
+    assert!(set.remove(5000));
+    assert!(set.spilled());
+    assert!(set.remove(128));
+    assert!(!set.spilled());
+    assert!(!set.contains(128));
+    assert_eq!(set, [0, 127].into_iter().collect());
+}
+
+#[test]
+fn operators() {
+    let a: ComboBitSet<1> = [1, 2, 3, 100, 200].into_iter().collect();
+    let b: ComboBitSet<1> = [2, 3, 4, 200].into_iter().collect();
+
+    assert_eq!((&a & &b).iter().collect::<Vec<_>>(), vec![2, 3, 200]);
+    assert_eq!(
+        (&a | &b).iter().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 100, 200]
+    );
+    assert_eq!((&a ^ &b).iter().collect::<Vec<_>>(), vec![1, 4, 100]);
+    assert_eq!((&a - &b).iter().collect::<Vec<_>>(), vec![1, 100]);
+
+    // The heap is trimmed when the high bits cancel out
+    let diff = &b - &a;
+    assert!(!diff.spilled());
+    assert_eq!(diff.iter().collect::<Vec<_>>(), vec![4]);
+
+    let mut c = a.clone();
+    c -= &a;
+    assert!(c.is_empty());
+    assert!(!c.spilled());
+}
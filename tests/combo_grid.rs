@@ -0,0 +1,66 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::ComboGrid;
+
+const EMPTY_GRID: ComboGrid<usize, 6> = ComboGrid::new();
+
+fn numbered(width: usize, height: usize) -> ComboGrid<usize, 6> {
+    let mut grid = EMPTY_GRID;
+    grid.resize(width, height, 0);
+    grid.iter_mut().for_each(|((x, y), val)| *val = y * 10 + x);
+    grid
+}
+
+#[test]
+fn resize_keeps_cells() {
+    let mut grid = numbered(3, 2);
+    assert!(!grid.spilled());
+
+    grid.resize(4, 3, 99);
+    assert!(grid.spilled());
+    assert_eq!(grid.row(0).unwrap().to_vec(), vec![0, 1, 2, 99]);
+    assert_eq!(grid.row(1).unwrap().to_vec(), vec![10, 11, 12, 99]);
+    assert_eq!(grid.row(2).unwrap().to_vec(), vec![99; 4]);
+
+    grid.resize(2, 2, 0);
+    assert!(!grid.spilled());
+    assert_eq!(grid, {
+        let mut expected = numbered(3, 2);
+        expected.resize(2, 2, 0);
+        expected
+    });
+    assert_eq!(
+        grid.iter().map(|(_, &val)| val).collect::<Vec<_>>(),
+        vec![0, 1, 10, 11]
+    );
+}
+
+#[test]
+fn rows_columns_and_neighbors() {
+    let grid = numbered(3, 2);
+    assert_eq!(grid.rows().count(), 2);
+    assert_eq!(
+        grid.columns()
+            .map(|col| col.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+        vec![vec![0, 10], vec![1, 11], vec![2, 12]]
+    );
+    assert_eq!(grid.column(3).count(), 0);
+
+    let around = grid
+        .neighbors(1, 0)
+        .map(|(_, &val)| val)
+        .collect::<Vec<_>>();
+    assert_eq!(around, vec![0, 2, 11]);
+    assert_eq!(grid.neighbors_with_diagonals(1, 0).count(), 5);
+}
+
+#[test]
+fn transpose() {
+    let grid = numbered(3, 2).transpose();
+    assert_eq!(grid.width(), 2);
+    assert_eq!(grid.height(), 3);
+    for ((x, y), &val) in grid.iter() {
+        assert_eq!(val, x * 10 + y);
+    }
+}
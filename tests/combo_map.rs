@@ -0,0 +1,85 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::{combo_map, ComboMap, ComboMapEntry};
+
+const EMPTY_MAP: ComboMap<i32, &str, 3> = combo_map! {};
+
+#[test]
+fn insert_get_remove() {
+    let mut map = EMPTY_MAP;
+    assert_eq!(map.insert(3, "c"), None);
+    assert_eq!(map.insert(1, "a"), None);
+    assert_eq!(map.insert(3, "C"), Some("c"));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&3), Some(&"C"));
+    assert_eq!(map.remove(&1), Some("a"));
+    assert_eq!(map.remove(&1), None);
+    assert!(!map.contains_key(&1));
+}
+
+#[test]
+fn spill_sorts_entries() {
+    let mut map = EMPTY_MAP;
+    for key in [5, 2, 4, 1, 3, 0] {
+        map.insert(key, "x");
+    }
+
+    assert!(map.spilled());
+    assert_eq!(
+        map.keys().copied().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4, 5]
+    );
+    (0..6).for_each(|key| assert!(map.contains_key(&key)));
+    assert!(!map.contains_key(&6));
+
+    assert_eq!(map.remove(&2), Some("x"));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![0, 1, 3, 4, 5]);
+    map.insert(2, "y");
+    assert_eq!(
+        map.keys().copied().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4, 5]
+    );
+    assert_eq!(map[&2], "y");
+}
+
+#[test]
+fn entry() {
+    let mut map = EMPTY_MAP;
+    map.insert(1, "a");
+
+    match map.entry(1) {
+        ComboMapEntry::Occupied(mut entry) => assert_eq!(entry.insert("b"), "a"),
+        ComboMapEntry::Vacant(_) => unreachable!(),
+    }
+
+    for key in 2..6 {
+        map.entry(key).or_insert("new");
+    }
+
+    assert_eq!(map.len(), 5);
+    assert_eq!(map[&1], "b");
+    assert_eq!(map[&5], "new");
+
+    let counts =
+        [1, 2, 1, 3, 1]
+            .into_iter()
+            .fold(ComboMap::<i32, usize, 2>::new(), |mut map, x| {
+                *map.entry(x).or_default() += 1;
+                map
+            });
+    assert_eq!(
+        counts.iter().collect::<Vec<_>>(),
+        vec![(&1, &3), (&2, &1), (&3, &1)]
+    );
+}
+
+#[test]
+fn from_iter_eq() {
+    let a = [(1, "a"), (2, "b"), (3, "c"), (4, "d")]
+        .into_iter()
+        .collect::<ComboMap<_, _, 3>>();
+    let b: ComboMap<i32, &str, 3> = combo_map! { 4 => "d", 3 => "c", 2 => "b", 1 => "a" };
+    assert_eq!(a, b);
+    println!("{a:?}");
+    assert_eq!(a.into_iter().count(), 4);
+}
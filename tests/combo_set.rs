@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::{combo_set, ComboSet};
+
+const EMPTY_SET: ComboSet<i32, 3> = combo_set![];
+
+#[test]
+fn insert_contains_remove() {
+    let mut set = EMPTY_SET;
+    assert!(set.insert(3));
+    assert!(set.insert(1));
+    assert!(!set.insert(3));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&3));
+    assert!(set.remove(&3));
+    assert!(!set.remove(&3));
+    assert!(!set.contains(&3));
+}
+
+#[test]
+fn spill_and_retain() {
+    let mut set = EMPTY_SET;
+    set.extend([5, 4, 3, 2, 1, 4]);
+    assert!(set.spilled());
+    assert_eq!(set.len(), 5);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+    set.retain(|x| x % 2 == 1);
+    assert!(!set.spilled());
+    assert!(set.contains(&5));
+    assert!(!set.contains(&4));
+}
+
+#[test]
+fn set_operations() {
+    let a: ComboSet<i32, 2> = combo_set![1, 2, 3, 4];
+    let b: ComboSet<i32, 4> = combo_set![3, 4, 5];
+
+    assert_eq!(
+        a.union(&b).copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert_eq!(a.intersection(&b).copied().collect::<Vec<_>>(), vec![3, 4]);
+    assert_eq!(a.difference(&b).copied().collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(
+        a.symmetric_difference(&b).copied().collect::<Vec<_>>(),
+        vec![1, 2, 5]
+    );
+
+    let sub: ComboSet<i32, 4> = a.intersection(&b).copied().collect();
+    assert!(sub.is_subset(&a));
+    assert!(sub.is_subset(&b));
+    assert!(!a.is_subset(&b));
+}
+
+#[test]
+fn order_insensitive_eq() {
+    let a: ComboSet<i32, 4> = combo_set![1, 2, 3];
+    let b: ComboSet<i32, 4> = combo_set![3, 1, 2];
+    assert_eq!(a, b);
+    assert_ne!(a, combo_set![1, 2]);
+    assert_eq!(format!("{a:?}"), "{1, 2, 3}");
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::ComboSlab;
+
+const EMPTY_SLAB: ComboSlab<i32, 2> = ComboSlab::new();
+
+#[test]
+fn insert_remove_reuse() {
+    let mut slab = EMPTY_SLAB;
+    let keys = (1..=5).map(|x| slab.insert(x)).collect::<Vec<_>>();
+    assert_eq!(keys, vec![0, 1, 2, 3, 4]);
+    assert_eq!(slab.stack_len(), 2);
+    assert_eq!(slab.heap_len(), 3);
+
+    assert_eq!(slab.remove(3), Some(4));
+    assert_eq!(slab.remove(0), Some(1));
+    assert_eq!(slab.len(), 3);
+    assert_eq!(slab[4], 5);
+
+    // free stack slots are used first
+    assert_eq!(slab.insert(6), 0);
+    assert_eq!(slab.insert(7), 3);
+    assert_eq!(slab.insert(8), 5);
+    assert_eq!(slab.get(3), Some(&7));
+}
+
+#[test]
+fn iter() {
+    let mut slab = EMPTY_SLAB;
+    (1..=4).for_each(|x| {
+        slab.insert(x);
+    });
+    slab.remove(1);
+    slab.remove(3);
+    println!("{slab:?}");
+
+    assert_eq!(slab.iter().collect::<Vec<_>>(), vec![(0, &1), (2, &3)]);
+    assert_eq!(slab.into_iter().collect::<Vec<_>>(), vec![(0, 1), (2, 3)]);
+}
@@ -138,3 +138,184 @@ fn exarr_macro() {
     println!("{item2}");
     assert_eq!(item2.len(), 3);
 }
+
+#[test]
+fn chunks_across_boundary() {
+    let mut cv = DEFAULT_TEST_REARR;
+    cv.extend([4, 5, 6, 7]);
+
+    let chunks = cv.chunks(2).map(|c| c.to_vec()).collect::<Vec<_>>();
+    assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5, 6], vec![7]]);
+
+    let chunks = cv.chunks_exact(3).map(|c| c.to_vec()).collect::<Vec<_>>();
+    assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    let chunks = cv.rchunks(3).map(|c| c.to_vec()).collect::<Vec<_>>();
+    assert_eq!(chunks, vec![vec![5, 6, 7], vec![2, 3, 4], vec![1]]);
+
+    let windows = cv.windows(6).map(|w| w.to_vec()).collect::<Vec<_>>();
+    assert_eq!(
+        windows,
+        vec![vec![1, 2, 3, 4, 5, 6], vec![2, 3, 4, 5, 6, 7]]
+    );
+    assert_eq!(cv.windows(8).count(), 0);
+}
+
+#[test]
+fn split_and_chunk_by() {
+    let mut cv = combo_vec![0, 1, 2];
+    cv.extend([0, 0, 3]);
+
+    let groups = cv
+        .split(|&x| x == 0)
+        .map(|g| g.to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(groups, vec![vec![], vec![1, 2], vec![], vec![3]]);
+
+    let runs = cv
+        .chunk_by(|a, b| a < b)
+        .map(|g| g.to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(runs, vec![vec![0, 1, 2], vec![0], vec![0, 3]]);
+
+    let empty = ComboVec::<i32, 3>::new();
+    assert_eq!(empty.split(|&x| x == 0).count(), 1);
+    assert_eq!(empty.chunk_by(|a, b| a == b).count(), 0);
+}
+
+#[test]
+fn dedup_refills_stack() {
+    let mut cv = combo_vec![1, 1, 1];
+    cv.extend([1, 2, 2, 3, 4, 4, 5]);
+    cv.dedup();
+    assert_eq!(cv.to_vec(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(cv.stack_len(), 3);
+    assert_eq!(cv.heap_len(), 2);
+
+    let mut cv = combo_vec![1, 2, 3];
+    cv.extend([3, 3, 4]);
+    cv.dedup_by(|a, b| a == b);
+    assert_eq!(cv.to_vec(), vec![1, 2, 3, 4]);
+    assert_eq!(cv.stack_len(), 3);
+    assert_eq!(cv.heap_len(), 1);
+}
+
+#[test]
+fn split_off_append_concat() {
+    let mut cv = DEFAULT_TEST_REARR;
+    cv.extend([4, 5, 6]);
+
+    let mut heap_tail = cv.split_off(4);
+    assert_eq!(cv.to_vec(), vec![1, 2, 3, 4]);
+    assert_eq!(heap_tail.to_vec(), vec![5, 6]);
+    assert_eq!(heap_tail.heap_len(), 0);
+
+    let mut stack_tail = cv.split_off(1);
+    assert_eq!(cv.to_vec(), vec![1]);
+    assert_eq!(stack_tail.to_vec(), vec![2, 3, 4]);
+    assert!(!stack_tail.spilled());
+
+    let mut wide: ComboVec<i32, 8> = ComboVec::new();
+    wide.append(&mut cv);
+    wide.append(&mut stack_tail);
+    wide.append(&mut heap_tail);
+    assert!(cv.is_empty() && stack_tail.is_empty() && heap_tail.is_empty());
+    assert_eq!(wide.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+    assert!(!wide.spilled());
+
+    let joined = ComboVec::<i32, 2>::concat(&[wide.clone(), wide]);
+    assert_eq!(joined.len(), 12);
+    assert_eq!(joined.stack_len(), 2);
+    assert_eq!(joined.to_vec(), vec![1, 2, 3, 4, 5, 6, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn into_capacity() {
+    let mut cv = DEFAULT_TEST_REARR;
+    cv.extend([4, 5, 6, 7]);
+
+    let same: ComboVec<i32, 5> = cv.into_capacity();
+    assert_eq!(same.stack_len(), 5);
+    assert_eq!(same.heap_len(), 2);
+
+    let shrunk: ComboVec<i32, 1> = same.into_capacity();
+    assert_eq!(shrunk.stack_len(), 1);
+    assert_eq!(shrunk.heap_len(), 6);
+    assert_eq!(shrunk.to_vec(), vec![1, 2, 3, 4, 5, 6, 7]);
+
+    let none: ComboVec<i32, 0> = shrunk.into_capacity();
+    assert_eq!(none.heap_len(), 7);
+    assert_eq!(none.first(), Some(&1));
+}
+
+#[test]
+fn insert_shifts_into_heap() {
+    let mut cv = DEFAULT_TEST_REARR;
+    cv.insert(0, 0);
+    assert_eq!(cv.to_vec(), vec![0, 1, 2, 3]);
+    assert_eq!(cv.stack_len(), 3);
+    assert_eq!(cv.heap_len(), 1);
+
+    cv.insert(4, 4);
+    cv.insert(3, 9);
+    assert_eq!(cv.to_vec(), vec![0, 1, 2, 9, 3, 4]);
+}
+
+#[test]
+fn extend_from_slice_fills_stack_then_heap() {
+    let mut cv = ComboVec::<i32, 4>::new();
+    cv.push(1);
+    cv.extend_from_slice(&[2, 3]);
+    assert_eq!(cv.stack_len(), 3);
+    assert!(!cv.spilled());
+
+    cv.extend_from_slice(&[4, 5, 6]);
+    assert_eq!(cv.stack_len(), 4);
+    assert_eq!(cv.heap_len(), 2);
+    assert_eq!(cv.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+
+    cv.extend_from_slice(&[]);
+    assert_eq!(cv.len(), 6);
+}
+
+#[test]
+fn extend_reserves_heap_once() {
+    use std::ptr;
+
+    let mut cv = ComboVec::<i32, 2>::new();
+    cv.extend(0..12);
+    assert_eq!(cv.stack_len(), 2);
+    assert_eq!(cv.heap_len(), 10);
+    assert!(cv.heap_capacity() >= 10);
+
+    // Once the heap has room for the whole iterator, extending never grows or moves it.
+    let mut cv = ComboVec::<i32, 2>::new();
+    cv.extend(0..3);
+    cv.reserve(9);
+    let capacity = cv.heap_capacity();
+    let heap = ptr::from_ref(&cv[2]);
+    cv.extend(3..12);
+    assert_eq!(cv.heap_len(), 10);
+    assert_eq!(cv.heap_capacity(), capacity);
+    assert_eq!(ptr::from_ref(&cv[2]), heap);
+
+    // An iterator without a useful size hint still fills the stack first.
+    let mut cv = ComboVec::<i32, 2>::new();
+    cv.extend((0..5).filter(|i| i % 2 == 0));
+    assert_eq!(cv.to_vec(), vec![0, 2, 4]);
+    assert_eq!(cv.heap_len(), 1);
+}
+
+#[test]
+fn clone_from_slice_reuses_heap() {
+    let mut cv = ComboVec::<String, 1>::new();
+    cv.extend(["a", "b", "c"].map(String::from));
+    let capacity = cv.heap_capacity();
+
+    cv.clone_from_slice(&[String::from("x"), String::from("y")]);
+    assert_eq!(cv.to_vec(), ["x", "y"]);
+    assert_eq!(cv.heap_capacity(), capacity);
+
+    cv.clone_from_slice(&[]);
+    assert!(cv.is_empty());
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "std")]
+
+use combo_vec::{combo_vec, ComboVec, ComboVecCursor};
+use std::io::{BufRead, ErrorKind, Read, Seek, SeekFrom, Write};
+
+#[test]
+fn write_spills_onto_heap() {
+    let mut frame = ComboVec::<u8, 4>::new();
+    frame.write_all(&[1, 2, 3]).unwrap();
+    assert!(!frame.spilled());
+
+    write!(frame, "{}", 45).unwrap();
+    assert!(frame.spilled());
+    assert_eq!(frame.to_vec(), [1, 2, 3, b'4', b'5']);
+}
+
+#[test]
+fn read_across_stack_and_heap() {
+    let mut cursor = ComboVecCursor::new((0..10).collect::<ComboVec<u8, 4>>());
+
+    let mut buf = [0; 6];
+    assert_eq!(cursor.read(&mut buf).unwrap(), 6);
+    assert_eq!(buf, [0, 1, 2, 3, 4, 5]);
+
+    let mut rest = Vec::new();
+    cursor.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, [6, 7, 8, 9]);
+    assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+}
+
+#[test]
+fn fill_buf_yields_each_side() {
+    let mut cursor = ComboVecCursor::new((0..6).collect::<ComboVec<u8, 4>>());
+
+    cursor.consume(1);
+    assert_eq!(cursor.fill_buf().unwrap(), &[1, 2, 3]);
+    cursor.consume(3);
+    assert_eq!(cursor.fill_buf().unwrap(), &[4, 5]);
+    cursor.consume(2);
+    assert!(cursor.fill_buf().unwrap().is_empty());
+}
+
+#[test]
+fn read_lines_through_boundary() {
+    let mut frame = ComboVec::<u8, 8>::new();
+    frame.write_all(b"first\nsecond\nthird").unwrap();
+
+    let lines = ComboVecCursor::new(frame)
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(lines, ["first", "second", "third"]);
+}
+
+#[test]
+fn seek() {
+    let mut cursor = ComboVecCursor::new(combo_vec![1u8, 2, 3, 4]);
+
+    assert_eq!(cursor.seek(SeekFrom::End(-1)).unwrap(), 3);
+    assert_eq!(cursor.seek(SeekFrom::Current(-2)).unwrap(), 1);
+    assert_eq!(cursor.seek(SeekFrom::Start(10)).unwrap(), 10);
+    assert!(cursor.fill_buf().unwrap().is_empty());
+
+    let err = cursor.seek(SeekFrom::Current(-11)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert_eq!(cursor.position(), 10);
+}
+
+#[test]
+fn get_mut_refreshes_stack_bytes() {
+    let mut cursor = ComboVecCursor::new((1..=6).collect::<ComboVec<u8, 4>>());
+
+    let mut byte = [0];
+    cursor.read_exact(&mut byte).unwrap();
+    assert_eq!(byte, [1]);
+
+    cursor.get_mut()[1] = 20;
+    cursor.get_mut()[3] = 40;
+
+    let mut rest = Vec::new();
+    cursor.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, [20, 3, 40, 5, 6]);
+
+    let mut copy = cursor.clone();
+    copy.set_position(0);
+    let mut bytes = Vec::new();
+    copy.read_to_end(&mut bytes).unwrap();
+    assert_eq!(bytes, [1, 20, 3, 40, 5, 6]);
+}
+
+#[test]
+fn read_byte_by_byte() {
+    let mut cursor = ComboVecCursor::new((0..=255).collect::<ComboVec<u8, 200>>());
+
+    for expected in 0..=255 {
+        let mut byte = [0];
+        cursor.read_exact(&mut byte).unwrap();
+        assert_eq!(byte, [expected]);
+    }
+    assert_eq!(cursor.read(&mut [0]).unwrap(), 0);
+}
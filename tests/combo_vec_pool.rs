@@ -0,0 +1,41 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::{ComboVecPool, PooledComboVec};
+
+#[test]
+fn buffers_are_reused() {
+    let pool = ComboVecPool::<u32, 2>::new();
+
+    let capacity = {
+        let mut items = pool.get();
+        items.extend(0..100);
+        items.heap_capacity()
+    };
+    assert_eq!(pool.available(), 1);
+
+    let mut items = pool.get();
+    assert!(items.is_empty());
+    assert_eq!(items.heap_capacity(), capacity);
+    assert_eq!(pool.available(), 0);
+
+    items.extend(0..100);
+    assert_eq!(items.heap_capacity(), capacity);
+}
+
+#[test]
+fn many_guards_at_once() {
+    let pool = ComboVecPool::<u32, 1>::new();
+    {
+        let mut a = pool.get();
+        let mut b = pool.get();
+        let c = pool.get();
+        a.extend([1, 2]);
+        b.extend([1, 2]);
+        drop(c);
+    }
+    assert_eq!(pool.available(), 2);
+
+    let kept = PooledComboVec::into_inner(pool.get());
+    assert!(kept.heap_capacity() > 0);
+    assert_eq!(pool.available(), 1);
+}
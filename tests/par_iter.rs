@@ -0,0 +1,49 @@
+#![cfg(feature = "rayon")]
+
+use combo_vec::{combo_vec, re_arr, ComboVec, ReArr};
+use rayon::prelude::*;
+
+#[test]
+fn combo_vec_par_iter() {
+    let mut x: ComboVec<u64, 8> = (0..100).collect();
+    assert!(x.spilled());
+
+    assert_eq!(x.par_iter().sum::<u64>(), 4950);
+    x.par_iter_mut().for_each(|val| *val *= 2);
+    assert_eq!(
+        x.par_iter().copied().collect::<Vec<_>>(),
+        (0..200).step_by(2).collect::<Vec<_>>()
+    );
+
+    let enumerated = x
+        .into_par_iter()
+        .enumerate()
+        .map(|(i, val)| val - 2 * i as u64);
+    assert!(enumerated.all(|val| val == 0));
+}
+
+#[test]
+fn combo_vec_collect_and_extend() {
+    let x: ComboVec<u32, 4> = (0..10).into_par_iter().collect();
+    assert_eq!(x.stack_len(), 4);
+    assert_eq!(x.to_vec(), (0..10).collect::<Vec<_>>());
+
+    let mut y = combo_vec![1, 2];
+    y.par_extend(vec![3, 4, 5]);
+    assert_eq!(y.to_vec(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn re_arr_par_iter() {
+    let mut x = re_arr![1, 2, 3; None, None];
+    assert_eq!(x.par_iter().len(), 3);
+    x.par_iter_mut().for_each(|val| *val += 1);
+    x.par_extend([10]);
+    assert_eq!(
+        x.clone().into_par_iter().collect::<Vec<_>>(),
+        vec![2, 3, 4, 10]
+    );
+
+    let y: ReArr<i32, 4> = x.into_par_iter().filter(|val| val % 2 == 0).collect();
+    assert_eq!(y.to_vec(), vec![2, 4, 10]);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "bytemuck")]
+
+use combo_vec::{re_arr, FromBytesError, PodArr, ReArr};
+
+#[test]
+fn bytes_round_trip() {
+    let mut arr = PodArr::<u32, 64>::new();
+    arr.try_extend_from_slice(&[1, 0xdead_beef, u32::MAX])
+        .unwrap();
+
+    let bytes = arr.as_bytes();
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(&bytes[4..8], &0xdead_beefu32.to_ne_bytes());
+
+    let copy = PodArr::<u32, 64>::try_from_bytes(bytes).unwrap();
+    assert_eq!(copy, arr);
+}
+
+#[test]
+fn from_unaligned_bytes() {
+    let mut buf = [0u8; 9];
+    buf[1..].copy_from_slice(&[1, 0, 0, 0, 2, 0, 0, 0]);
+
+    let arr = PodArr::<u32, 2>::try_from_bytes(&buf[1..]).unwrap();
+    assert_eq!(arr.as_slice(), &[u32::from_le(1), u32::from_le(2)]);
+}
+
+#[test]
+fn from_bytes_errors() {
+    assert_eq!(
+        PodArr::<u32, 4>::try_from_bytes(&[0; 6]),
+        Err(FromBytesError::Slop)
+    );
+
+    let Err(FromBytesError::Capacity(err)) = PodArr::<u32, 1>::try_from_bytes(&[0; 8]) else {
+        panic!("expected a capacity error");
+    };
+    assert_eq!(err.required(), 2);
+    assert_eq!(err.capacity(), 1);
+}
+
+#[test]
+fn empty_bytes() {
+    let arr = PodArr::<u64, 4>::try_from_bytes(&[]).unwrap();
+    assert!(arr.is_empty());
+    assert!(arr.as_bytes().is_empty());
+}
+
+#[test]
+fn pop_and_truncate_zero_slots() {
+    let mut arr = PodArr::<u8, 4>::new();
+    arr.try_extend_from_slice(&[1, 2, 3, 4]).unwrap();
+    assert!(arr.try_push(5).is_err());
+
+    assert_eq!(arr.pop(), Some(4));
+    arr.truncate(1);
+    arr.push(9);
+    assert_eq!(arr.as_bytes(), &[1, 9]);
+
+    // Stale elements are zeroed, so equal arrays compare and hash the same.
+    assert_eq!(arr, PodArr::try_from_bytes(&[1, 9]).unwrap());
+}
+
+#[test]
+fn re_arr_conversions() {
+    let re: ReArr<u16, 4> = re_arr![1, 2, 3; None];
+    let pod = PodArr::from(re.clone());
+    assert_eq!(pod.as_slice(), &[1, 2, 3]);
+    assert_eq!(ReArr::from(pod), re);
+}
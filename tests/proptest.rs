@@ -0,0 +1,51 @@
+#![cfg(feature = "proptest")]
+
+use combo_vec::{ComboVec, ReArr};
+use proptest::{
+    prelude::*,
+    strategy::ValueTree,
+    test_runner::{TestCaseError, TestError, TestRunner},
+};
+
+proptest! {
+    #[test]
+    fn re_arr_stays_within_capacity(arr in any::<ReArr<u8, 4>>()) {
+        prop_assert!(arr.len() <= 4);
+    }
+
+    #[test]
+    fn combo_vec_round_trips_through_vec(vec in any::<ComboVec<u16, 4>>()) {
+        prop_assert!(vec.len() <= 9);
+        prop_assert_eq!(vec.iter().copied().collect::<ComboVec<_, 4>>(), vec);
+    }
+}
+
+#[test]
+fn combo_vec_covers_both_sides_of_capacity() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = any::<ComboVec<u8, 4>>();
+    let vecs = (0..200)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .collect::<Vec<_>>();
+
+    assert!(vecs.iter().any(ComboVec::spilled));
+    assert!(vecs.iter().any(|vec| !vec.spilled()));
+}
+
+#[test]
+fn spilling_failure_shrinks_to_smallest_spilled_len() {
+    let mut runner = TestRunner::deterministic();
+    let result = runner.run(&any::<ComboVec<u8, 4>>(), |vec| {
+        if vec.spilled() {
+            Err(TestCaseError::fail("spilled"))
+        } else {
+            Ok(())
+        }
+    });
+
+    let Err(TestError::Fail(_, vec)) = result else {
+        panic!("expected the property to fail");
+    };
+    assert_eq!(vec.len(), 5);
+    assert!(vec.iter().all(|&val| val == 0));
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "quickcheck")]
+
+use combo_vec::{ComboVec, ReArr};
+use quickcheck::{Arbitrary, Gen};
+
+#[test]
+fn re_arr_stays_within_capacity() {
+    let mut g = Gen::new(100);
+    for _ in 0..100 {
+        assert!(ReArr::<u8, 4>::arbitrary(&mut g).len() <= 4);
+    }
+}
+
+#[test]
+fn combo_vec_covers_both_sides_of_capacity() {
+    let mut g = Gen::new(100);
+    let vecs = (0..200)
+        .map(|_| ComboVec::<u8, 4>::arbitrary(&mut g))
+        .collect::<Vec<_>>();
+
+    assert!(vecs.iter().all(|vec| vec.len() <= 9));
+    assert!(vecs.iter().any(ComboVec::spilled));
+    assert!(vecs.iter().any(|vec| !vec.spilled()));
+}
+
+#[test]
+fn shrink_tries_the_boundary_first() {
+    let vec = (0..8u8).collect::<ComboVec<_, 4>>();
+    let mut shrunk = vec.shrink();
+
+    assert_eq!(shrunk.next().unwrap().len(), 5);
+    assert_eq!(shrunk.next().unwrap().len(), 4);
+    assert!(shrunk.all(|vec| vec.len() <= 8));
+}
+
+#[test]
+fn spilling_failure_shrinks_to_smallest_spilled_len() {
+    fn prop(vec: &ComboVec<u8, 4>) -> bool {
+        !vec.spilled()
+    }
+
+    let mut failing = (0..9u8).collect::<ComboVec<_, 4>>();
+    assert!(!prop(&failing));
+
+    // Follow the first failing shrink like quickcheck does.
+    while let Some(smaller) = failing.shrink().find(|vec| !prop(vec)) {
+        failing = smaller;
+    }
+
+    assert_eq!(failing.len(), 5);
+}
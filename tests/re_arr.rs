@@ -1,4 +1,6 @@
-use combo_vec::{re_arr, ReArr};
+use combo_vec::{assert_stack_size, re_arr, ReArr};
+
+assert_stack_size!(ReArr<u8, 16>, 64);
 
 const DEFAULT_TEST_REARR: ReArr<i32, 5> = re_arr![1, 2, 3; None, None];
 const EMPTY_STRING_ALLOC: ReArr<String, 3> = re_arr![];
@@ -118,3 +120,90 @@ fn exarr_macro() {
     println!("{item2}");
     assert_eq!(item2.len(), 3);
 }
+
+#[test]
+fn dedup() {
+    let mut cv = re_arr![1, 1, 2, 3, 3; None];
+    cv.dedup();
+    assert_eq!(cv.len(), 3);
+    assert_eq!(cv.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    assert_eq!(cv.get(3), None);
+
+    cv.push(3);
+    cv.dedup_by_key(|x| *x % 2);
+    assert_eq!(cv.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+}
+
+#[test]
+fn split_off_append() {
+    let mut cv = DEFAULT_TEST_REARR;
+    let mut tail = cv.split_off(1);
+    assert_eq!(cv.len(), 1);
+    assert_eq!(tail.iter().collect::<Vec<_>>(), vec![&2, &3]);
+
+    let mut small = re_arr![4, 5];
+    tail.append(&mut small);
+    assert!(small.is_empty());
+    assert_eq!(tail.len(), 4);
+
+    let mut too_many = re_arr![6, 7];
+    let err = tail.try_append(&mut too_many).unwrap_err();
+    assert_eq!(err.required(), 6);
+    assert_eq!(err.capacity(), 5);
+    assert_eq!(tail.len(), 4);
+    assert_eq!(too_many.len(), 2);
+
+    cv.append(&mut tail);
+    assert_eq!(cv.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+}
+
+#[test]
+fn into_capacity() {
+    let cv = DEFAULT_TEST_REARR;
+    let bigger: ReArr<i32, 16> = cv.into_capacity();
+    assert_eq!(bigger.len(), 3);
+    assert_eq!(bigger.capacity(), 16);
+
+    let exact = bigger.try_into_capacity::<3>().unwrap();
+    assert_eq!(exact.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+
+    let err = exact.try_into_capacity::<2>().unwrap_err();
+    assert_eq!(err.required(), 3);
+    assert_eq!(err.capacity(), 2);
+    assert_eq!(err.into_inner().len(), 3);
+}
+
+#[test]
+fn fmt_write() {
+    use core::fmt::Write;
+
+    let mut buf = ReArr::<u8, 6>::new();
+    write!(buf, "{}-{}", 12, 3).unwrap();
+    assert_eq!(buf.iter().copied().collect::<Vec<_>>(), b"12-3");
+
+    assert!(buf.write_str("abc").is_err());
+    assert!(buf.write_str("ab").is_ok());
+    assert_eq!(buf.len(), 6);
+}
+
+#[test]
+fn extend_from_slice() {
+    let mut cv = DEFAULT_TEST_REARR;
+    cv.extend_from_slice(&[4]);
+    assert_eq!(cv.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+
+    let err = cv.try_extend_from_slice(&[5, 6]).unwrap_err();
+    assert_eq!(err.required(), 6);
+    assert_eq!(err.capacity(), 5);
+    assert_eq!(cv.len(), 4);
+
+    cv.clone_from_slice(&[9, 8]);
+    assert_eq!(cv.iter().collect::<Vec<_>>(), vec![&9, &8]);
+}
+
+#[test]
+#[should_panic(expected = "required capacity of 6 exceeds the array capacity of 5")]
+fn extend_from_slice_overflow() {
+    let mut cv = DEFAULT_TEST_REARR;
+    cv.extend_from_slice(&[4, 5, 6]);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "embedded-io")]
+
+use combo_vec::{re_arr, CapacityError, ReArr, ReArrReader};
+use embedded_io::{Error, ErrorKind, Read, ReadExactError, Write};
+
+#[test]
+fn write_fills_then_errors() {
+    let mut buf = ReArr::<u8, 4>::new();
+    assert_eq!(buf.write(&[1, 2]), Ok(2));
+    assert_eq!(buf.write(&[3, 4, 5]), Ok(2));
+
+    let err = buf.write(&[5]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(err.required(), 5);
+    assert_eq!(buf.write(&[]), Ok(0));
+    assert_eq!(buf.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn write_all_reports_capacity() {
+    let mut buf = ReArr::<u8, 3>::new();
+    let err: CapacityError = buf.write_all(b"abcd").unwrap_err();
+    assert_eq!(err.capacity(), 3);
+    assert_eq!(buf.len(), 3);
+}
+
+#[test]
+fn reader() {
+    let frame = re_arr![1u8, 2, 3, 4; None];
+    let mut reader = ReArrReader::new(&frame);
+
+    let mut two = [0; 2];
+    reader.read_exact(&mut two).unwrap();
+    assert_eq!(two, [1, 2]);
+    assert_eq!(reader.position(), 2);
+    assert_eq!(reader.remaining(), 2);
+
+    let mut three = [0; 3];
+    assert_eq!(
+        reader.read_exact(&mut three),
+        Err(ReadExactError::UnexpectedEof)
+    );
+    assert_eq!(reader.read(&mut three), Ok(0));
+}
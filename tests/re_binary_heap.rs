@@ -0,0 +1,28 @@
+use combo_vec::{re_arr, ReBinaryHeap, ReBinaryHeapPeekMut};
+
+const EMPTY_HEAP: ReBinaryHeap<i32, 4> = ReBinaryHeap::new();
+
+#[test]
+fn push_pop() {
+    let mut heap = EMPTY_HEAP;
+    heap.extend([5, 1, 8, 3]);
+    assert_eq!(heap.try_push(9).unwrap_err().into_inner(), 9);
+
+    assert_eq!(heap.pop(), Some(8));
+    assert_eq!(heap.pop(), Some(5));
+    assert_eq!(heap.pop(), Some(3));
+    assert_eq!(heap.pop(), Some(1));
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn heapify_sort_and_peek_mut() {
+    let mut heap = ReBinaryHeap::from(re_arr![2, 7, 4, 1]);
+    assert_eq!(heap.peek(), Some(&7));
+
+    *heap.peek_mut().unwrap() = 0;
+    assert_eq!(heap.peek(), Some(&4));
+    assert_eq!(ReBinaryHeapPeekMut::pop(heap.peek_mut().unwrap()), 4);
+
+    assert_eq!(heap.into_sorted_arr(), re_arr![0, 1, 2; None]);
+}
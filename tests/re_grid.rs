@@ -0,0 +1,54 @@
+use combo_vec::ReGrid;
+
+const EMPTY_GRID: ReGrid<usize, 4, 3> = ReGrid::new();
+
+fn numbered(width: usize, height: usize) -> ReGrid<usize, 4, 3> {
+    let mut grid = EMPTY_GRID;
+    grid.resize(width, height, 0);
+    grid.iter_mut().for_each(|((x, y), val)| *val = y * 10 + x);
+    grid
+}
+
+#[test]
+fn resize_keeps_cells() {
+    let mut grid = numbered(3, 2);
+    grid.resize(4, 3, 99);
+    assert_eq!(grid[(3, 0)], 99);
+    assert_eq!(grid[(2, 1)], 12);
+    assert_eq!(grid[(0, 2)], 99);
+
+    grid.resize(2, 1, 0);
+    assert_eq!(
+        grid.iter().map(|(_, &val)| val).collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+    assert_eq!(grid.get(2, 0), None);
+}
+
+#[test]
+#[should_panic = "new width cannot be greater than the row capacity"]
+fn resize_past_capacity() {
+    let mut grid = EMPTY_GRID;
+    grid.resize(5, 1, 0);
+}
+
+#[test]
+fn columns_neighbors_and_transpose() {
+    let grid = numbered(3, 2);
+    assert_eq!(
+        grid.columns()
+            .map(|col| col.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+        vec![vec![0, 10], vec![1, 11], vec![2, 12]]
+    );
+    assert_eq!(
+        grid.neighbors(2, 1).map(|(pos, _)| pos).collect::<Vec<_>>(),
+        vec![(2, 0), (1, 1)]
+    );
+
+    let grid: ReGrid<usize, 3, 4> = grid.transpose();
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    for ((x, y), &val) in grid.iter() {
+        assert_eq!(val, x * 10 + y);
+    }
+}
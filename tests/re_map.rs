@@ -0,0 +1,52 @@
+use combo_vec::{re_map, ReMap, ReMapEntry};
+
+const EMPTY_MAP: ReMap<i32, &str, 3> = re_map! {};
+
+#[test]
+fn insert_get_remove() {
+    let mut map = EMPTY_MAP;
+    assert_eq!(map.insert(3, "c"), None);
+    assert_eq!(map.insert(1, "a"), None);
+    assert_eq!(map.insert(3, "C"), Some("c"));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&3), Some(&"C"));
+    assert_eq!(map.remove(&1), Some("a"));
+    assert_eq!(map.remove(&1), None);
+    assert!(!map.contains_key(&1));
+}
+
+#[test]
+fn full_map() {
+    let mut map = EMPTY_MAP;
+    map.extend([(1, "a"), (2, "b"), (3, "c")]);
+    assert_eq!(map.try_insert(3, "d"), Ok(Some("c")));
+
+    let err = map.try_insert(4, "d").unwrap_err();
+    assert_eq!(err.capacity(), 3);
+    assert_eq!(err.into_inner(), (4, "d"));
+}
+
+#[test]
+fn entry() {
+    let mut map = EMPTY_MAP;
+    map.insert(1, "a");
+
+    match map.entry(1) {
+        ReMapEntry::Occupied(entry) => assert_eq!(entry.remove(), "a"),
+        ReMapEntry::Vacant(_) => unreachable!(),
+    }
+
+    map.entry(2).or_insert("b");
+    map.entry(2).and_modify(|v| *v = "B").or_insert("c");
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&2, &"B")]);
+}
+
+#[test]
+fn from_iter_eq() {
+    let a = [(1, "a"), (2, "b"), (3, "c")]
+        .into_iter()
+        .collect::<ReMap<_, _, 3>>();
+    let b: ReMap<i32, &str, 3> = re_map! { 3 => "c", 2 => "b", 1 => "a" };
+    assert_eq!(a, b);
+    println!("{a:?}");
+}
@@ -0,0 +1,142 @@
+use combo_vec::{re_ring, ReRing};
+use std::collections::VecDeque;
+
+const TELEMETRY: ReRing<u64, 4> = re_ring![];
+
+#[test]
+fn keeps_the_newest_samples() {
+    let mut ring = TELEMETRY;
+
+    for sample in 0..10 {
+        let evicted = ring.push(sample);
+        assert_eq!(evicted, sample.checked_sub(4));
+        assert_eq!(ring.latest(), Some(&sample));
+    }
+
+    assert!(ring.is_full());
+    assert_eq!(ring.len(), 4);
+    assert_eq!(ring.oldest(), Some(&6));
+    assert!(ring.iter().eq(&[6, 7, 8, 9]));
+    assert!(ring.iter().rev().eq(&[9, 8, 7, 6]));
+}
+
+#[test]
+fn matches_vec_deque_model() {
+    let mut ring = ReRing::<u32, 5>::new();
+    let mut dense = ReRing::<u32, 5, u32>::default();
+    let mut model = VecDeque::new();
+
+    for step in 0..500u32 {
+        match step % 5 {
+            0 => {
+                assert_eq!(ring.pop_oldest(), model.front().copied());
+                assert_eq!(dense.pop_oldest(), model.pop_front());
+            }
+            1 if step % 3 == 0 => {
+                assert_eq!(ring.pop_latest(), model.back().copied());
+                assert_eq!(dense.pop_latest(), model.pop_back());
+            }
+            _ => {
+                let evicted = (model.len() == 5).then(|| model.pop_front()).flatten();
+                model.push_back(step);
+                assert_eq!(ring.push(step), evicted);
+                assert_eq!(dense.push(step), evicted);
+            }
+        }
+
+        assert_eq!(ring.len(), model.len());
+        assert_eq!(ring.oldest(), model.front());
+        assert_eq!(ring.latest(), model.back());
+        assert!(ring.iter().eq(&model));
+        assert_eq!(dense.oldest(), model.front());
+        assert_eq!(dense.latest(), model.back());
+        assert!(dense.iter().eq(&model));
+
+        let (older, newer) = dense.as_slices();
+        assert_eq!(older.len() + newer.len(), model.len());
+        assert!(older.iter().chain(newer).eq(&model));
+
+        for (idx, val) in model.iter().enumerate() {
+            assert_eq!(ring.get(idx), Some(val));
+            assert_eq!(ring[idx], *val);
+            assert_eq!(dense.get(idx), Some(val));
+            assert_eq!(dense[idx], *val);
+        }
+        assert_eq!(ring.get(model.len()), None);
+        assert_eq!(dense.get(model.len()), None);
+    }
+}
+
+#[test]
+fn wrapping_slices_and_make_contiguous() {
+    let mut ring = ReRing::<i32, 4, i32>::from(re_ring![1, 2, 3; None]);
+    assert_eq!(ring.as_slices(), (&[1, 2, 3][..], &[][..]));
+
+    ring.push(4);
+    ring.push(5);
+    ring.push(6);
+    assert_eq!(ring.as_slices(), (&[3, 4][..], &[5, 6][..]));
+
+    let contiguous = ring.make_contiguous();
+    assert_eq!(contiguous, &[3, 4, 5, 6]);
+    contiguous.reverse();
+    assert_eq!(ring.as_slices(), (&[6, 5, 4, 3][..], &[][..]));
+    assert_eq!(ring.push(7), Some(6));
+    assert!(ring.iter().eq(&[5, 4, 3, 7]));
+    assert_eq!(ReRing::<i32, 4>::from(ring), re_ring![5, 4, 3, 7]);
+}
+
+#[test]
+fn mutate_in_place() {
+    let mut ring = (1..=6).collect::<ReRing<i32, 4>>();
+    ring[0] *= 10;
+    *ring.get_mut(3).unwrap() *= 10;
+    ring.iter_mut().for_each(|x| *x += 1);
+    assert!(ring.iter().eq(&[31, 5, 6, 61]));
+    assert_eq!(ring.get_mut(4), None);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn index_out_of_bounds() {
+    let ring = re_ring![1, 2; None];
+    let _ = ring[2];
+}
+
+#[test]
+fn zero_capacity() {
+    let mut ring: ReRing<i32, 0> = re_ring![];
+    assert_eq!(ring.push(1), Some(1));
+    assert!(ring.is_empty());
+    assert!(ring.is_full());
+    assert_eq!(ring.latest(), None);
+
+    let mut dense = ReRing::<i32, 0, i32>::default();
+    assert_eq!(dense.push(1), Some(1));
+    assert_eq!(dense.as_slices(), (&[][..], &[][..]));
+    assert_eq!(dense.make_contiguous(), &[]);
+}
+
+#[test]
+fn into_iter_is_chronological() {
+    let mut ring = re_ring![String::from("a"), String::from("b"), String::from("c")];
+    ring.push(String::from("d"));
+    assert_eq!(ring.clone().into_iter().collect::<String>(), "bcd");
+    assert_eq!(ring.into_iter().rev().collect::<String>(), "dcb");
+}
+
+#[test]
+fn clear_and_eq() {
+    // The same elements can start at different places in the underlying array.
+    let mut a = re_ring![1, 2, 3];
+    a.push(4);
+    let b = re_ring![2, 3, 4];
+    assert_eq!(a, b);
+    assert_eq!(a.to_string(), "[2, 3, 4]");
+
+    a.clear();
+    assert!(a.is_empty());
+    assert_eq!(a, ReRing::default());
+    assert_eq!(a.push(5), None);
+    assert!(a.iter().eq(&[5]));
+}
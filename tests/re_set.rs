@@ -0,0 +1,49 @@
+use combo_vec::{re_set, ReSet};
+
+const EMPTY_SET: ReSet<i32, 3> = re_set![];
+
+#[test]
+fn insert_contains_remove() {
+    let mut set = EMPTY_SET;
+    assert!(set.insert(3));
+    assert!(set.insert(1));
+    assert!(!set.insert(3));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&3));
+    assert!(set.remove(&3));
+    assert!(!set.remove(&3));
+    assert!(!set.contains(&3));
+}
+
+#[test]
+fn full_set() {
+    let mut set = EMPTY_SET;
+    set.extend([1, 2, 3]);
+    assert_eq!(set.try_insert(3), Ok(false));
+
+    let err = set.try_insert(4).unwrap_err();
+    assert_eq!(err.capacity(), 3);
+    assert_eq!(err.into_inner(), 4);
+}
+
+#[test]
+fn set_operations_and_retain() {
+    let mut a: ReSet<i32, 4> = re_set![1, 2, 3, 4];
+    let b: ReSet<i32, 3> = re_set![3, 4, 5];
+
+    assert_eq!(
+        a.union(&b).copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert_eq!(a.intersection(&b).copied().collect::<Vec<_>>(), vec![3, 4]);
+    assert_eq!(a.difference(&b).copied().collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(
+        a.symmetric_difference(&b).copied().collect::<Vec<_>>(),
+        vec![1, 2, 5]
+    );
+    assert!(!a.is_subset(&b));
+
+    a.retain(|x| *x > 2);
+    assert!(a.is_subset(&b));
+    assert_eq!(a, re_set![4, 3]);
+}
@@ -0,0 +1,36 @@
+use combo_vec::ReSlab;
+
+const EMPTY_SLAB: ReSlab<i32, 3> = ReSlab::new();
+
+#[test]
+fn insert_remove_reuse() {
+    let mut slab = EMPTY_SLAB;
+    let a = slab.insert(1);
+    let b = slab.insert(2);
+    let c = slab.insert(3);
+    assert_eq!((a, b, c), (0, 1, 2));
+    assert_eq!(slab.vacant_key(), None);
+    assert!(slab.try_insert(4).is_err());
+
+    assert_eq!(slab.remove(b), Some(2));
+    assert_eq!(slab.remove(b), None);
+    assert_eq!(slab.len(), 2);
+    assert_eq!(slab[a], 1);
+    assert_eq!(slab[c], 3);
+
+    assert_eq!(slab.insert(5), b);
+    assert_eq!(slab.get(b), Some(&5));
+}
+
+#[test]
+fn iter() {
+    let mut slab = EMPTY_SLAB;
+    slab.insert(1);
+    let key = slab.insert(2);
+    slab.insert(3);
+    slab.remove(key);
+    println!("{slab:?}");
+
+    assert_eq!(slab.iter().collect::<Vec<_>>(), vec![(0, &1), (2, &3)]);
+    assert_eq!(slab.into_iter().collect::<Vec<_>>(), vec![(0, 1), (2, 3)]);
+}
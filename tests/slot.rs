@@ -0,0 +1,93 @@
+use combo_vec::{re_arr, ReArr};
+
+#[test]
+fn dense_storage_has_no_tag() {
+    assert_eq!(
+        size_of::<ReArr<u32, 64, u32>>(),
+        size_of::<[u32; 64]>() + size_of::<usize>()
+    );
+    assert_eq!(
+        size_of::<ReArr<f64, 16, f64>>(),
+        size_of::<[f64; 16]>() + size_of::<usize>()
+    );
+    assert!(size_of::<ReArr<u32, 64, u32>>() < size_of::<ReArr<u32, 64>>());
+    assert!(size_of::<ReArr<f64, 2048, f64>>() < size_of::<ReArr<f64, 2048>>());
+    assert_eq!(
+        size_of::<ReArr<u32, 64, Option<u32>>>(),
+        size_of::<ReArr<u32, 64>>()
+    );
+}
+
+#[test]
+fn dense_and_tagged_behave_the_same() {
+    let mut dense = ReArr::<String, 4, String>::default();
+    let mut tagged = ReArr::<String, 4>::new();
+
+    for word in ["a", "b", "c"] {
+        dense.push(word.to_string());
+        tagged.push(word.to_string());
+    }
+
+    dense.insert(0, "z".to_string());
+    tagged.insert(0, "z".to_string());
+    assert!(dense.try_extend_from_slice(&["full".to_string()]).is_err());
+    assert!(tagged.try_extend_from_slice(&["full".to_string()]).is_err());
+
+    assert_eq!(dense.remove(1), "a");
+    assert_eq!(tagged.remove(1), "a");
+    assert_eq!(dense.pop().as_deref(), Some("c"));
+    assert_eq!(tagged.pop().as_deref(), Some("c"));
+    assert_eq!(dense.last(), tagged.last());
+
+    dense.retain(|s| s != "b");
+    tagged.retain(|s| s != "b");
+    assert!(dense.iter().eq(tagged.iter()));
+
+    let dense = dense.into_iter().collect::<Vec<_>>();
+    let tagged = tagged.into_iter().collect::<Vec<_>>();
+    assert_eq!(dense, ["z"]);
+    assert_eq!(dense, tagged);
+}
+
+#[test]
+fn truncate_resets_slots() {
+    let mut arr = [1u8, 2, 3].into_iter().collect::<ReArr<u8, 4, u8>>();
+    arr.truncate(1);
+    arr.extend([7]);
+    assert_eq!(arr.as_slice(), &[1, 7]);
+    assert_eq!(arr, [1, 7].into_iter().collect());
+
+    arr.clear();
+    assert!(arr.is_empty());
+    assert_eq!(arr.get(0), None);
+}
+
+#[test]
+fn dense_slice_methods() {
+    let mut arr = [3, 1, 2, 2].into_iter().collect::<ReArr<i32, 6, i32>>();
+    arr.as_mut_slice().sort_unstable();
+    arr.dedup();
+    assert_eq!(arr.as_slice(), &[1, 2, 3]);
+
+    let tail = arr.split_off(1);
+    assert_eq!(tail.as_slice(), &[2, 3]);
+    assert_eq!(arr.swap_remove(0), 1);
+    assert!(arr.is_empty());
+    assert_eq!(arr.first(), None);
+}
+
+#[test]
+fn re_arr_conversions() {
+    let re: ReArr<i32, 4> = re_arr![1, 2, 3; None];
+    let dense = ReArr::<i32, 4, i32>::from(re.clone());
+    assert_eq!(dense.as_slice(), &[1, 2, 3]);
+    assert_eq!(ReArr::from(dense), re);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 1) should be < len (is 1)")]
+fn remove_past_len() {
+    let mut arr = ReArr::<u8, 4, u8>::default();
+    arr.push(1);
+    arr.remove(1);
+}
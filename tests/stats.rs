@@ -0,0 +1,92 @@
+#![cfg(feature = "stats")]
+
+use combo_vec::{combo_vec, ComboVec, SpillStats, HISTOGRAM_BUCKETS};
+
+#[test]
+fn tracked_pushes() {
+    let stats = SpillStats::new();
+
+    for len in [2, 3, 6] {
+        let mut vec = ComboVec::<u32, 3>::new();
+        (0..len).for_each(|i| vec.push_tracked(i, &stats));
+    }
+
+    let snapshot = stats.snapshot();
+    assert_eq!(snapshot.pushes(), 11);
+    assert_eq!(snapshot.heap_pushes(), 3);
+    assert_eq!(snapshot.spills(), 1);
+    assert_eq!(snapshot.max_len(), 6);
+
+    let histogram = snapshot.histogram();
+    assert_eq!(histogram.len(), HISTOGRAM_BUCKETS);
+    assert_eq!(histogram[1], 3);
+    assert_eq!(histogram[2], 5);
+    assert_eq!(histogram[3], 3);
+    assert_eq!(histogram.iter().sum::<usize>(), snapshot.pushes());
+}
+
+#[test]
+fn zero_capacity_spills_immediately() {
+    let stats = SpillStats::new();
+    let mut vec = ComboVec::<u8, 0>::new();
+    vec.push_tracked(1, &stats);
+    vec.push_tracked(2, &stats);
+
+    let snapshot = stats.snapshot();
+    assert_eq!(snapshot.heap_pushes(), 2);
+    assert_eq!(snapshot.spills(), 1);
+}
+
+#[test]
+fn global_records_push_and_extend() {
+    let before = SpillStats::global().snapshot();
+
+    let mut vec = combo_vec![1, 2; None];
+    vec.push(3);
+    vec.extend([4, 5]);
+
+    // Other tests may push concurrently, so only lower bounds can be checked.
+    let after = SpillStats::global().snapshot();
+    assert!(after.pushes() >= before.pushes() + 3);
+    assert!(after.heap_pushes() >= before.heap_pushes() + 2);
+    assert!(after.spills() > before.spills());
+    assert!(after.max_len() >= 5);
+}
+
+#[test]
+fn global_records_bulk_growth() {
+    let before = SpillStats::global().snapshot();
+
+    let mut vec = ComboVec::<u16, 4>::new();
+    vec.extend_from_slice(&[0; 100]);
+    vec.extend(100..600);
+    let mut other = (600..1000).collect::<ComboVec<u16, 8>>();
+    vec.append(&mut other);
+    assert_eq!(vec.len(), 1000);
+
+    // Every bulk call counts the same as pushing its elements one at a time.
+    let after = SpillStats::global().snapshot();
+    assert!(after.pushes() >= before.pushes() + 1000);
+    assert!(after.heap_pushes() >= before.heap_pushes() + 996);
+    assert!(after.spills() > before.spills());
+    assert!(after.max_len() >= 1000);
+
+    // Lengths 512..=1000 fall in the same bucket.
+    assert!(after.histogram()[10] >= before.histogram()[10] + 489);
+    assert!(after.histogram()[7] >= before.histogram()[7] + 64);
+}
+
+#[test]
+fn reset_and_dump() {
+    let stats = SpillStats::new();
+    let mut vec = ComboVec::<u8, 1>::new();
+    (0..4).for_each(|i| vec.push_tracked(i, &stats));
+
+    assert_eq!(
+        stats.snapshot().to_string(),
+        "pushes: 4, heap pushes: 3, spills: 1, max len: 4\nlen 1: 1\nlen 2..=3: 2\nlen 4..=7: 1\n"
+    );
+
+    stats.reset();
+    assert_eq!(stats.snapshot(), SpillStats::new().snapshot());
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "alloc")]
+
+use combo_vec::TailComboVec;
+
+#[test]
+fn newest_elements_stay_on_the_stack() {
+    let mut tail_vec = TailComboVec::<i32, 4>::new();
+
+    for i in 0..100 {
+        let heap_len = tail_vec.heap_len();
+        tail_vec.push(i);
+        assert_eq!(tail_vec.last(), Some(&i));
+        assert_eq!(tail_vec.stack_len(), tail_vec.len().min(4));
+
+        // Once the stack is full, every push moves exactly one element onto the heap.
+        if tail_vec.len() > 4 {
+            assert_eq!(tail_vec.heap_len(), heap_len + 1);
+        }
+    }
+
+    assert_eq!(tail_vec.len(), 100);
+    assert_eq!(tail_vec.heap_len(), 96);
+    assert_eq!(tail_vec.first(), Some(&0));
+    assert_eq!(tail_vec.to_vec(), (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn matches_vec_model() {
+    let mut tail_vec = TailComboVec::<u32, 5>::new();
+    let mut model = Vec::new();
+
+    // Walk up and down across the stack boundary with an uneven mix of pushes and pops.
+    for step in 0..2000u32 {
+        if step % 7 < 4 || step % 300 < 150 {
+            tail_vec.push(step);
+            model.push(step);
+        } else {
+            assert_eq!(tail_vec.pop(), model.pop());
+        }
+
+        assert_eq!(tail_vec.len(), model.len());
+        assert_eq!(tail_vec.stack_len(), model.len().min(5));
+        assert_eq!(tail_vec.last(), model.last());
+        assert_eq!(tail_vec.first(), model.first());
+    }
+
+    assert!(tail_vec.iter().eq(&model));
+    for (idx, val) in model.iter().enumerate() {
+        assert_eq!(tail_vec.get(idx), Some(val));
+        assert_eq!(tail_vec[idx], *val);
+    }
+    assert_eq!(tail_vec.get(model.len()), None);
+
+    while let Some(val) = model.pop() {
+        assert_eq!(tail_vec.pop(), Some(val));
+    }
+    assert_eq!(tail_vec.pop(), None);
+}
+
+#[test]
+fn zero_stack_capacity() {
+    let mut tail_vec = (1..=3).collect::<TailComboVec<i32, 0>>();
+    assert_eq!(tail_vec.heap_len(), 3);
+    assert_eq!(tail_vec.last(), Some(&3));
+    assert_eq!(tail_vec.pop(), Some(3));
+    assert_eq!(tail_vec.into_vec(), vec![1, 2]);
+}
+
+#[test]
+fn truncate_and_mutate() {
+    let mut tail_vec = (1..=9).collect::<TailComboVec<i32, 4>>();
+    assert!(tail_vec.spilled());
+
+    tail_vec.truncate(20);
+    assert_eq!(tail_vec.len(), 9);
+
+    // The newest remaining elements are moved back onto the stack.
+    tail_vec.truncate(6);
+    assert_eq!(tail_vec.stack_len(), 4);
+    assert_eq!(tail_vec.heap_len(), 2);
+
+    tail_vec[0] = 10;
+    *tail_vec.get_mut(1).unwrap() = 20;
+    tail_vec.iter_mut().for_each(|x| *x += 1);
+    assert_eq!(tail_vec.to_vec(), vec![11, 21, 4, 5, 6, 7]);
+
+    tail_vec.truncate(1);
+    assert_eq!(tail_vec.to_vec(), vec![11]);
+    assert_eq!(tail_vec.stack_len(), 1);
+
+    tail_vec.clear();
+    assert!(tail_vec.is_empty());
+}
+
+#[test]
+fn into_iter_owned() {
+    let tail_vec = ["a", "b", "c", "d", "e"]
+        .into_iter()
+        .map(String::from)
+        .collect::<TailComboVec<String, 2>>();
+
+    assert_eq!(tail_vec.clone().into_vec(), ["a", "b", "c", "d", "e"]);
+    assert_eq!(tail_vec.into_iter().rev().collect::<String>(), "edcba");
+}
+
+#[test]
+fn eq_hash_and_fmt() {
+    use std::collections::HashSet;
+
+    let a = (1..=4).collect::<TailComboVec<i32, 2>>();
+    let mut b = (1..=5).collect::<TailComboVec<i32, 2>>();
+    b.pop();
+    assert_eq!(a, b);
+
+    let set = [a, b].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 1);
+
+    let c = (1..=3).collect::<TailComboVec<i32, 2>>();
+    assert_eq!(c.to_string(), "[1, 2, 3]");
+    assert_eq!(TailComboVec::<i32, 2>::default().to_string(), "[]");
+}